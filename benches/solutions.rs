@@ -0,0 +1,69 @@
+//! Benchmarks for the parse and solve phases of every day, run against the
+//! bundled puzzle inputs with `cargo bench`.
+
+use std::hint::black_box;
+use std::rc::Rc;
+
+use advent_of_code_2022::sorted_topk::SortedTopK;
+use advent_of_code_2022::{day01, day02, day03, day04, day05, day06, day07, day08, input};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing and both parts of a day, plus any extra benchmarks
+/// given as `name => |parsed_input| expression`.
+macro_rules! bench_day {
+    ($name:ident, $day:literal, $module:ident $(, $extra:literal => |$input:ident| $body:expr)*) => {
+        fn $name(c: &mut Criterion) {
+            let contents = input::read(None, $day).unwrap();
+            let parsed = $module::parse(contents.as_bytes()).unwrap();
+
+            let mut group = c.benchmark_group(stringify!($module));
+            group.bench_function("parse", |b| {
+                b.iter(|| $module::parse(black_box(contents.as_bytes())).unwrap())
+            });
+            group.bench_function("part1", |b| b.iter(|| $module::part1(black_box(&parsed)).unwrap()));
+            group.bench_function("part2", |b| b.iter(|| $module::part2(black_box(&parsed)).unwrap()));
+            $(
+                group.bench_function($extra, |b| {
+                    let $input = &parsed;
+                    b.iter(|| $body)
+                });
+            )*
+            group.finish();
+        }
+    };
+}
+
+bench_day!(bench_day01, 1, day01, "sorted_topk_push" => |elves| {
+    let mut topk = SortedTopK::<i32>::new(3);
+    for elf in black_box(elves) {
+        topk.push(elf.calories());
+    }
+    topk
+});
+bench_day!(bench_day02, 2, day02);
+bench_day!(bench_day03, 3, day03, "common_items" => |rucksacks| {
+    day03::common_items(black_box(rucksacks).iter().map(day03::Rucksack::item_set))
+});
+bench_day!(bench_day04, 4, day04);
+bench_day!(bench_day05, 5, day05);
+bench_day!(bench_day06, 6, day06, "find_marker" => |chars| {
+    day06::find_marker(black_box(chars), 14)
+});
+bench_day!(bench_day07, 7, day07, "get_filesize" => |filesystem| {
+    let mut directory_sizes = vec![];
+    day07::get_filesize(Rc::clone(&black_box(filesystem).root), &mut directory_sizes)
+});
+bench_day!(bench_day08, 8, day08);
+
+criterion_group!(
+    benches,
+    bench_day01,
+    bench_day02,
+    bench_day03,
+    bench_day04,
+    bench_day05,
+    bench_day06,
+    bench_day07,
+    bench_day08
+);
+criterion_main!(benches);
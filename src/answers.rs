@@ -0,0 +1,95 @@
+//! Registry of verified answers, keyed by day and by the SHA-256 of the
+//! puzzle input they were verified against.
+//!
+//! The registry lives in `answers.toml` and looks like:
+//!
+//! ```toml
+//! [[answer]]
+//! day = 1
+//! input = "<sha256 of resources/day01.txt>"
+//! part1 = "68292"
+//! part2 = "203203"
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::Result;
+
+#[derive(Deserialize, Debug, Default)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Answer>,
+}
+
+/// The verified answers for one day's puzzle input.
+#[derive(Deserialize, Debug)]
+pub struct Answer {
+    pub day: u8,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Outcome of comparing a computed answer against the registry.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+/// Path of the registry committed alongside the bundled inputs.
+pub fn default_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Hex encoded SHA-256 of the puzzle input.
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+        let answers = toml::from_str(&contents).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {err}", path.display()),
+            )
+        })?;
+        Ok(answers)
+    }
+
+    /// The verified answer for the given day and part of the given input, if any.
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        let hash = hash(input);
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.day == day && entry.input == hash)?;
+        match part {
+            1 => entry.part1.as_deref(),
+            2 => entry.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2022::answers::{self, Answers, Verdict};
+use advent_of_code_2022::input::{self, InputArgs};
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::runner::{self, Solution};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use serde::Serialize;
+
+/// Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for a day, a single part of a day, or every day.
+    Run(RunArgs),
+    /// Check every solution against the bundled inputs and the verified answers.
+    Check(CheckArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to run. Both parts are run when omitted.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every implemented day against its bundled input.
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Report how long parsing and solving took alongside each answer.
+    #[arg(long)]
+    time: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// One `Day NN Part N: <answer>` line per solution.
+    Text,
+    /// One JSON object per line per solution.
+    Json,
+}
+
+/// The outcome of running a single solution, as printed by `--format json`.
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_ns: Option<u64>,
+    input_path: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Record {
+    fn print(&self, format: Format, time: bool) {
+        let (day, part) = (self.day, self.part);
+        match (format, &self.answer, &self.error) {
+            (Format::Json, _, _) => println!("{}", serde_json::to_string(self).unwrap()),
+            (Format::Text, Some(answer), _) if time => {
+                let parse = Duration::from_nanos(self.parse_ns.unwrap_or_default());
+                let solve = Duration::from_nanos(self.solve_ns.unwrap_or_default());
+                println!(
+                    "Day {day:02} Part {part}: {answer} (parse {parse:.2?}, solve {solve:.2?})"
+                )
+            }
+            (Format::Text, Some(answer), _) => println!("Day {day:02} Part {part}: {answer}"),
+            (Format::Text, None, error) => {
+                eprintln!(
+                    "Day {day:02} Part {part}: error: {}",
+                    error.as_deref().unwrap_or_default()
+                )
+            }
+        }
+    }
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Path to the registry of verified answers. Defaults to `answers.toml`.
+    #[arg(long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let solutions: Vec<&Solution> = match args.day {
+        Some(day) => runner::find(day, args.part).collect(),
+        None => runner::SOLUTIONS.iter().collect(),
+    };
+
+    if solutions.is_empty() {
+        eprintln!("no solution found for day {}", args.day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for (day, solutions) in &solutions.into_iter().group_by(|s| s.day) {
+        // read the input once per day so that both parts can share stdin
+        let input = args.input.read(day);
+
+        for solution in solutions {
+            let start = Instant::now();
+            let outcome = match &input {
+                Ok(input) => solution.run(input).map_err(|err| err.to_string()),
+                Err(err) => Err(err.to_string()),
+            };
+            let elapsed_ns = start.elapsed().as_nanos() as u64;
+
+            let record = match outcome {
+                Ok(solved) => Record {
+                    day,
+                    part: solution.part,
+                    answer: Some(solved.answer),
+                    elapsed_ns,
+                    parse_ns: Some(solved.parse_time.as_nanos() as u64),
+                    solve_ns: Some(solved.solve_time.as_nanos() as u64),
+                    input_path: args.input.path(day),
+                    error: None,
+                },
+                Err(err) => Record {
+                    day,
+                    part: solution.part,
+                    answer: None,
+                    elapsed_ns,
+                    parse_ns: None,
+                    solve_ns: None,
+                    input_path: args.input.path(day),
+                    error: Some(err),
+                },
+            };
+            failed |= record.error.is_some();
+            record.print(args.format, args.time);
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check(args: &CheckArgs) -> ExitCode {
+    let path = args.answers.clone().unwrap_or_else(answers::default_path);
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for (day, solutions) in &runner::SOLUTIONS.iter().group_by(|s| s.day) {
+        let input = match input::read(None, day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {day:02}: error: {err}");
+                failed += solutions.count();
+                continue;
+            }
+        };
+
+        for solution in solutions {
+            let part = solution.part;
+            let answer = match solution.run(&input) {
+                Ok(solved) => solved.answer,
+                Err(err) => {
+                    println!("Day {day:02} Part {part}: error: {err}");
+                    failed += 1;
+                    continue;
+                }
+            };
+            match answers.check(day, part, &input, &answer) {
+                Verdict::Pass => {
+                    println!("Day {day:02} Part {part}: pass");
+                    passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!("Day {day:02} Part {part}: FAIL expected {expected} but got {answer}");
+                    failed += 1;
+                }
+                Verdict::Missing => {
+                    let hash = answers::hash(&input);
+                    println!("Day {day:02} Part {part}: missing answer {answer} for input {hash}");
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.verbosity.init();
+
+    match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Check(args) => check(args),
+    }
+}
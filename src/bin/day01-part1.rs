@@ -1,31 +1,18 @@
-use std::error::Error;
-use std::fs::File;
-use std::io::{self, BufRead};
+use advent_of_code_2022::day01;
+use advent_of_code_2022::runner::SolverArgs;
+use advent_of_code_2022::Error;
+use clap::Parser;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let filepath = env!("CARGO_MANIFEST_DIR").to_string() + "/resources/day01.txt";
-    let file = File::open(filepath).unwrap();
+fn main() -> Result<(), Error> {
+    let args = SolverArgs::parse();
+    args.verbosity.init();
 
-    let mut current = 0;
-    let mut max = 0;
+    let contents = args.input.read(1)?;
 
-    for line in io::BufReader::new(file).lines() {
-        let line = line.unwrap();
-        if line == "" {
-            max = if current > max { current } else { max };
+    let input = day01::parse(contents.as_bytes())?;
+    let answer = day01::part1(&input)?;
 
-            current = 0;
-        } else {
-            let number: i32 = line.parse().unwrap();
-            current += number;
-        }
-    }
-
-    // in case the file does not end with an empty line,
-    // we need to check if the last collection is larger than the max
-    max = if current > max { current } else { max };
-
-    println!("{}", max);
+    println!("{answer}");
 
     Ok(())
 }
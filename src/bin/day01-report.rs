@@ -0,0 +1,45 @@
+use std::io;
+
+use advent_of_code_2022::day01::{self, InventoryReport};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::report::{Format, Report};
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Analyses the calories carried by every elf in the inventory.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// How to print the report. CSV lists only the per-elf rows, leaving out
+    /// the summary statistics and the histogram.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Number of elves to list as carrying the most calories.
+    #[arg(long, default_value_t = 3)]
+    top: usize,
+
+    /// Number of buckets in the histogram.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+    buckets: u16,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(1)?;
+
+    let elves = day01::parse(contents.as_bytes())?;
+    let report = InventoryReport::new(&elves, args.top, args.buckets as usize)?;
+
+    report.write(args.format, &mut io::stdout().lock())?;
+
+    Ok(())
+}
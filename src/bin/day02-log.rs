@@ -0,0 +1,44 @@
+use std::io;
+
+use advent_of_code_2022::day02::{self, GameLog, Strategy};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::report::{Format, Report};
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Replays the strategy guide round by round, followed by the game's statistics.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// Part whose reading of the strategy guide to follow.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// How to print the game log.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(2)?;
+
+    let rounds = day02::parse(contents.as_bytes())?;
+    let strategy = match args.part {
+        1 => Strategy::SHAPES,
+        _ => Strategy::Outcome,
+    };
+    let log = GameLog::new(&rounds, strategy)?;
+
+    log.write(args.format, &mut io::stdout().lock())?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use std::io;
+
+use advent_of_code_2022::day02::{self, StrategyReport};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::report::{Format, Report};
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Scores every possible reading of the strategy guide's second column.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// How to print the report.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(2)?;
+
+    let rounds = day02::parse(contents.as_bytes())?;
+    let report = StrategyReport::new(&rounds)?;
+
+    report.write(args.format, &mut io::stdout().lock())?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use std::io;
+
+use advent_of_code_2022::day03::{self, GroupReport};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::report::{Format, Report};
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Lists the items shared by each group of elves, flagging the groups without
+/// exactly one badge.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// Number of elves in each group.
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u16).range(1..))]
+    group_size: u16,
+
+    /// How to print the report.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(3)?;
+
+    let rucksacks = day03::parse(contents.as_bytes())?;
+    let report = GroupReport::new(&rucksacks, args.group_size as usize)?;
+
+    report.write(args.format, &mut io::stdout().lock())?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022::day03::{self, Validation};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Reports every malformed rucksack, then solves both parts.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// How to treat malformed rucksacks while solving.
+    #[arg(long, value_enum, default_value_t = Validation::Strict)]
+    validation: Validation,
+}
+
+fn main() -> Result<ExitCode, Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(3)?;
+
+    let rucksacks = day03::parse(contents.as_bytes())?;
+    let problems = day03::validate(&rucksacks);
+    for malformed in &problems {
+        println!("{}", Error::from(malformed));
+    }
+    println!(
+        "{} problems found in {} rucksacks",
+        problems.len(),
+        rucksacks.len()
+    );
+
+    // every malformed rucksack has been listed above, so do not warn about
+    // them again while solving
+    let validation = match args.validation {
+        Validation::Lenient => Validation::Quiet,
+        validation => validation,
+    };
+    let mut failed = false;
+    let answers = [
+        day03::compartment_total(&rucksacks, validation),
+        day03::badge_total(&rucksacks, 3, validation),
+    ];
+    for (part, answer) in answers.into_iter().enumerate() {
+        match answer {
+            Ok(answer) => println!("Part {}: {answer}", part + 1),
+            Err(err) => {
+                eprintln!("Part {}: error: {err}", part + 1);
+                failed = true;
+            }
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
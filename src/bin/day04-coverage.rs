@@ -0,0 +1,43 @@
+use std::io;
+
+use advent_of_code_2022::day04::{self, CoverageReport};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::interval::SectionRange;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::report::{Format, Report};
+use advent_of_code_2022::Error;
+use clap::Parser;
+
+/// Analyses how the assignments of every elf cover the sections of the camp.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// Sections making up the camp, e.g. `1-99`. Defaults to the sections from
+    /// the lowest to the highest assigned.
+    #[arg(long, value_name = "START-END")]
+    camp: Option<SectionRange>,
+
+    /// How to print the report. The CSV lists how many elves cover each run
+    /// of sections.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(4)?;
+
+    let pairs = day04::parse(contents.as_bytes())?;
+    let report = CoverageReport::new(&pairs, args.camp)?;
+
+    report.write(args.format, &mut io::stdout().lock())?;
+
+    Ok(())
+}
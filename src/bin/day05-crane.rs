@@ -0,0 +1,107 @@
+use std::io::{self, IsTerminal};
+use std::num::NonZeroU32;
+use std::thread;
+use std::time::Duration;
+
+use advent_of_code_2022::day05::{
+    self, CappedCrane, Crane, CrateMover9000, CrateMover9001, Highlight,
+};
+use advent_of_code_2022::input::InputArgs;
+use advent_of_code_2022::logging::Verbosity;
+use advent_of_code_2022::Error;
+use clap::{Parser, ValueEnum};
+
+/// Rearranges the crates with the selected crane model.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// Crane model to rearrange the crates with.
+    #[arg(long, value_enum, default_value_t = Model::CrateMover9000)]
+    crane: Model,
+
+    /// Use a crane lifting at most this many crates at once instead.
+    #[arg(long, conflicts_with = "crane")]
+    capacity: Option<NonZeroU32>,
+
+    /// Redraw the ship after every move, highlighting the stacks moved from
+    /// and to along with the moved crates.
+    #[arg(long)]
+    animate: bool,
+
+    /// Milliseconds to wait between moves while animating.
+    #[arg(long, default_value_t = 300, requires = "animate")]
+    delay: u64,
+
+    /// Only check that every move can be carried out, without moving crates.
+    #[arg(long, conflicts_with = "animate")]
+    dry_run: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum Model {
+    /// Moves crates one at a time.
+    #[value(name = "9000")]
+    CrateMover9000,
+    /// Moves several crates at once.
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    args.verbosity.init();
+
+    let contents = args.input.read(5)?;
+
+    let procedure = day05::parse(contents.as_bytes())?;
+    if args.dry_run {
+        day05::dry_run(&procedure)?;
+        let moves = procedure.instructions.len();
+        println!("All {moves} moves can be carried out");
+        return Ok(());
+    }
+
+    let crane: Box<dyn Crane> = match (args.capacity, args.crane) {
+        (Some(capacity), _) => Box::new(CappedCrane(capacity)),
+        (None, Model::CrateMover9000) => Box::new(CrateMover9000),
+        (None, Model::CrateMover9001) => Box::new(CrateMover9001),
+    };
+    let answer = if args.animate {
+        // only clear the screen and colour the drawing in a terminal, so that
+        // piping the animation to a file leaves a readable log of every move
+        let terminal = io::stdout().is_terminal();
+        let moves = procedure.instructions.len();
+        day05::rearrange_with(&procedure, crane.as_ref(), &mut |ship, step| {
+            if terminal {
+                print!("\x1b[2J\x1b[H");
+            }
+            let drawing = match step {
+                Some((i, instruction)) => {
+                    println!("Move {}/{moves}: {instruction}", i + 1);
+                    if terminal {
+                        ship.draw_highlighted(&Highlight::from(instruction))
+                    } else {
+                        ship.to_string()
+                    }
+                }
+                None => {
+                    println!("Move 0/{moves}");
+                    ship.to_string()
+                }
+            };
+            println!("{drawing}");
+            thread::sleep(Duration::from_millis(args.delay));
+        })?
+    } else {
+        day05::rearrange(&procedure, crane.as_ref())?
+    };
+
+    println!("Top of Each Stack from Left to Right: {answer}");
+
+    Ok(())
+}
@@ -0,0 +1,369 @@
+//! Day 1: Calorie Counting
+
+use std::io::{self, BufRead, Write};
+
+use crate::paragraphs::paragraphs;
+use crate::report::Report;
+use crate::sorted_topk::{SortedTopKByKey, SortedTopKN};
+use crate::{Error, Result};
+
+/// An elf carrying a number of food items, each with some number of calories.
+#[derive(Debug)]
+pub struct Elf {
+    pub items: Vec<i32>,
+}
+
+impl Elf {
+    pub fn calories(&self) -> i32 {
+        self.items.iter().sum()
+    }
+}
+
+/// Groups the inventory into elves, one per blank-line-separated group.
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<Elf>> {
+    let mut elves = vec![];
+
+    for paragraph in paragraphs(reader) {
+        let paragraph = paragraph?;
+        let items = paragraph
+            .numbered()
+            .map(|(i, line)| {
+                line.trim()
+                    .parse()
+                    .map_err(|err| Error::parse(1, i, 1, line, format!("invalid calories: {err}")))
+            })
+            .collect::<Result<_>>()?;
+        elves.push(Elf { items });
+    }
+
+    Ok(elves)
+}
+
+/// Calories carried by the elf carrying the most calories.
+pub fn part1(elves: &[Elf]) -> Result<i32> {
+    let mut max = 0;
+
+    for elf in elves {
+        let current = elf.calories();
+        max = if current > max { current } else { max };
+    }
+
+    Ok(max)
+}
+
+/// Calories carried by the top three elves combined, or by all of them when
+/// there are fewer than three.
+pub fn part2(elves: &[Elf]) -> Result<i32> {
+    let topk: SortedTopKN<i32, 3> = elves.iter().map(Elf::calories).collect();
+
+    Ok(topk.into_sorted_vec().into_iter().sum::<i32>())
+}
+
+/// Percentiles reported alongside the mean and median.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Longest bar drawn in the histogram.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// The calories carried by one elf, numbered from 1 in input order.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub elf: usize,
+    pub items: usize,
+    pub calories: i32,
+    /// 1 for the elf carrying the most, elves carrying the same share a rank.
+    pub rank: usize,
+}
+
+/// Number of elves carrying between `start` and `end` calories, inclusive.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+/// Analysis of the calories carried by every elf in the inventory.
+#[derive(Debug)]
+pub struct InventoryReport {
+    /// Every elf, in input order.
+    pub elves: Vec<ElfTotal>,
+    pub mean: f64,
+    pub median: f64,
+    /// Nearest-rank value of each of the [`PERCENTILES`].
+    pub percentiles: Vec<(u8, i32)>,
+    pub histogram: Vec<Bucket>,
+    /// Number of the elves ranked within the top k, best first. Holds more
+    /// than k elves when several are tied for k-th place.
+    pub top: Vec<usize>,
+    /// Calories carried by more than one elf, along with the numbers of those elves.
+    pub ties: Vec<(i32, Vec<usize>)>,
+    k: usize,
+}
+
+impl InventoryReport {
+    pub fn new(elves: &[Elf], k: usize, buckets: usize) -> Result<Self> {
+        if elves.is_empty() {
+            return Err(Error::EmptyInput { day: 1 });
+        }
+
+        let mut totals: Vec<ElfTotal> = elves
+            .iter()
+            .enumerate()
+            .map(|(i, elf)| ElfTotal {
+                elf: i + 1,
+                items: elf.items.len(),
+                calories: elf.calories(),
+                rank: 0,
+            })
+            .collect();
+
+        // elf numbers ordered by calories, most first and ties in input order
+        let mut by_calories: Vec<usize> = (0..totals.len()).collect();
+        by_calories.sort_by_key(|&i| std::cmp::Reverse(totals[i].calories));
+
+        let mut ties: Vec<(i32, Vec<usize>)> = vec![];
+        for (position, &i) in by_calories.iter().enumerate() {
+            let calories = totals[i].calories;
+            let prev = position
+                .checked_sub(1)
+                .map(|prev| &totals[by_calories[prev]])
+                .map(|prev| (prev.elf, prev.calories, prev.rank));
+            match prev {
+                Some((prev_elf, prev_calories, prev_rank)) if prev_calories == calories => {
+                    totals[i].rank = prev_rank;
+                    match ties.last_mut() {
+                        Some((tied, elves)) if *tied == calories => elves.push(i + 1),
+                        _ => ties.push((calories, vec![prev_elf, i + 1])),
+                    }
+                }
+                _ => totals[i].rank = position + 1,
+            }
+        }
+
+        // the top k elves, along with any elves tied with the last of them
+        let mut topk = SortedTopKByKey::new(k, |total: &&ElfTotal| total.calories);
+        topk.extend(&totals);
+        let mut top: Vec<&ElfTotal> = topk.into_sorted_vec();
+        if let Some(last) = top.last().map(|total| total.calories) {
+            let tied: Vec<&ElfTotal> = totals
+                .iter()
+                .filter(|total| total.calories == last)
+                .filter(|total| !top.iter().any(|t| t.elf == total.elf))
+                .collect();
+            top.extend(tied);
+        }
+        top.sort_by_key(|total| (std::cmp::Reverse(total.calories), total.elf));
+        let top = top.iter().map(|total| total.elf).collect();
+
+        let sorted: Vec<i32> = by_calories
+            .iter()
+            .rev()
+            .map(|&i| totals[i].calories)
+            .collect();
+        let n = sorted.len();
+        let mean = sorted.iter().map(|&c| c as f64).sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+        } else {
+            sorted[n / 2] as f64
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, sorted[rank - 1])
+            })
+            .collect();
+
+        let (min, max) = (sorted[0] as i64, sorted[n - 1] as i64);
+        let width = ((max - min + 1) as u64).div_ceil(buckets.max(1) as u64) as i64;
+        let mut histogram: Vec<Bucket> = (0..buckets.max(1) as i64)
+            .map(|b| Bucket {
+                start: min + b * width,
+                end: min + (b + 1) * width - 1,
+                count: 0,
+            })
+            .take_while(|bucket| bucket.start <= max)
+            .collect();
+        for &calories in &sorted {
+            histogram[((calories as i64 - min) / width) as usize].count += 1;
+        }
+
+        Ok(Self {
+            elves: totals,
+            mean,
+            median,
+            percentiles,
+            histogram,
+            top,
+            ties,
+            k,
+        })
+    }
+}
+
+impl Report for InventoryReport {
+    fn write_table(&self, w: &mut dyn Write) -> io::Result<()> {
+        let total: i64 = self.elves.iter().map(|e| e.calories as i64).sum();
+        writeln!(w, "Elves:  {}", self.elves.len())?;
+        writeln!(w, "Total:  {total}")?;
+        writeln!(w, "Mean:   {:.2}", self.mean)?;
+        writeln!(w, "Median: {:.1}", self.median)?;
+        for (p, calories) in &self.percentiles {
+            writeln!(w, "P{p:<5} {calories}")?;
+        }
+
+        writeln!(w, "\nHistogram:")?;
+        let most = self.histogram.iter().map(|b| b.count).max().unwrap_or(1);
+        for bucket in &self.histogram {
+            let bar = "#".repeat((bucket.count * HISTOGRAM_WIDTH).div_ceil(most));
+            writeln!(
+                w,
+                "{:>8} - {:<8} {:>5} {bar}",
+                bucket.start, bucket.end, bucket.count
+            )?;
+        }
+
+        writeln!(w, "\nTop {}:", self.k)?;
+        for &elf in &self.top {
+            let total = &self.elves[elf - 1];
+            writeln!(
+                w,
+                "{:>4}. Elf {:<5} {}",
+                total.rank, total.elf, total.calories
+            )?;
+        }
+
+        writeln!(w, "\nTies:")?;
+        if self.ties.is_empty() {
+            writeln!(w, "  none")?;
+        }
+        for (calories, elves) in &self.ties {
+            let elves: Vec<String> = elves.iter().map(usize::to_string).collect();
+            writeln!(w, "{calories:>8} carried by elves {}", elves.join(", "))?;
+        }
+
+        writeln!(
+            w,
+            "\n{:>5} {:>5} {:>8} {:>5}",
+            "Elf", "Items", "Calories", "Rank"
+        )?;
+        for total in &self.elves {
+            writeln!(
+                w,
+                "{:>5} {:>5} {:>8} {:>5}",
+                total.elf, total.items, total.calories, total.rank
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "elf,items,calories,rank,top_k,tied")?;
+        for total in &self.elves {
+            let tied = self.ties.iter().any(|(c, _)| *c == total.calories);
+            writeln!(
+                w,
+                "{},{},{},{},{},{tied}",
+                total.elf,
+                total.items,
+                total.calories,
+                total.rank,
+                total.rank <= self.k,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn parse_groups_items_by_elf() {
+        let elves = parse(EXAMPLE.as_bytes()).unwrap();
+        let calories: Vec<i32> = elves.iter().map(Elf::calories).collect();
+        assert_eq!(calories, vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn parse_tolerates_blank_lines_and_crlf() {
+        let input = "\r\n1000\r\n2000\r\n\r\n\r\n3000";
+        let elves = parse(input.as_bytes()).unwrap();
+        let calories: Vec<i32> = elves.iter().map(Elf::calories).collect();
+        assert_eq!(calories, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn example_part1() {
+        let elves = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&elves).unwrap(), 24000);
+    }
+
+    #[test]
+    fn example_part2() {
+        let elves = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&elves).unwrap(), 45000);
+    }
+
+    #[test]
+    fn part2_with_fewer_than_three_elves() {
+        let elves = parse("1000\n\n2000\n".as_bytes()).unwrap();
+        assert_eq!(part2(&elves).unwrap(), 3000);
+    }
+
+    #[test]
+    fn inventory_report() {
+        let elves = parse(EXAMPLE.as_bytes()).unwrap();
+        let report = InventoryReport::new(&elves, 3, 4).unwrap();
+        assert_eq!(report.top, vec![4, 3, 5]);
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(report.percentiles[0], (10, 4000));
+        let counts: Vec<usize> = report.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![2, 2, 0, 1]);
+        assert!(report.ties.is_empty());
+    }
+
+    #[test]
+    fn inventory_report_ties() {
+        let elves = parse("3000\n\n1000\n\n3000\n\n2000\n1000\n".as_bytes()).unwrap();
+        let report = InventoryReport::new(&elves, 1, 10).unwrap();
+        let ranks: Vec<usize> = report.elves.iter().map(|e| e.rank).collect();
+        assert_eq!(ranks, vec![1, 4, 1, 1]);
+        assert_eq!(report.top, vec![1, 3, 4]);
+        assert_eq!(report.ties, vec![(3000, vec![1, 3, 4])]);
+
+        let mut csv = vec![];
+        report.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+elf,items,calories,rank,top_k,tied
+1,1,3000,1,true,true
+2,1,1000,4,false,false
+3,1,3000,1,true,true
+4,2,3000,1,true,true
+"
+        );
+    }
+}
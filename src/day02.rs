@@ -0,0 +1,479 @@
+//! Day 2: Rock Paper Scissors
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use log::debug;
+use parse_display::{Display, FromStr};
+
+use crate::error;
+use crate::report::Report;
+use crate::rps::{Game, Outcome};
+use crate::{Error, Result};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MyShape(pub Shape);
+
+#[derive(Debug, Copy, Clone)]
+pub struct OpponentShape(pub Shape);
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// The column is the shape I should play, X, Y and Z being the given shapes.
+    Shapes([Shape; 3]),
+    /// The column is the outcome I should end up with.
+    Outcome,
+}
+
+impl Strategy {
+    /// The reading of part 1, X being Rock, Y being Paper and Z being Scissors.
+    pub const SHAPES: Self = Self::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]);
+
+    /// Every possible reading: the six ways of mapping X, Y and Z to shapes,
+    /// followed by reading the column as the outcome.
+    pub fn all() -> Vec<Self> {
+        [Shape::Rock, Shape::Paper, Shape::Scissors]
+            .into_iter()
+            .permutations(3)
+            .map(|shapes| Self::Shapes([shapes[0], shapes[1], shapes[2]]))
+            .chain([Self::Outcome])
+            .collect()
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shapes([x, y, z]) => write!(f, "X={x:?} Y={y:?} Z={z:?}"),
+            Self::Outcome => write!(f, "X=Loss Y=Tie Z=Win"),
+        }
+    }
+}
+
+/// The second column of the strategy guide. Part 1 reads it as the shape I
+/// should play while part 2 reads it as the result I should end up with.
+#[derive(Display, FromStr, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+/// A single line of the strategy guide.
+#[derive(Debug)]
+pub struct Round {
+    pub opponent: OpponentShape,
+    pub column: Column,
+}
+
+impl FromStr for OpponentShape {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s {
+            "A" => Ok(Self(Shape::Rock)),
+            "B" => Ok(Self(Shape::Paper)),
+            "C" => Ok(Self(Shape::Scissors)),
+            _ => Err(format!("invalid opponent shape: {}", s)),
+        }
+    }
+}
+
+impl From<Column> for Outcome {
+    fn from(column: Column) -> Self {
+        match column {
+            Column::X => Self::Loss,
+            Column::Y => Self::Tie,
+            Column::Z => Self::Win,
+        }
+    }
+}
+
+/// The classic game of Rock, Paper, Scissors.
+pub fn game() -> Game<Shape> {
+    Game::new(vec![Shape::Rock, Shape::Paper, Shape::Scissors]).expect("the classic game is valid")
+}
+
+/// Points scored for the outcome of a round.
+pub fn outcome_score(outcome: Outcome) -> i32 {
+    match outcome {
+        Outcome::Loss => 0,
+        Outcome::Tie => 3,
+        Outcome::Win => 6,
+    }
+}
+
+impl Round {
+    /// The shape I play and the outcome of the round when following the strategy.
+    pub fn play(&self, game: &Game<Shape>, strategy: Strategy) -> Result<(MyShape, Outcome)> {
+        let opponent = self.opponent.0;
+        match strategy {
+            Strategy::Shapes(shapes) => {
+                let me = MyShape(shapes[self.column as usize]);
+                Ok((me, game.outcome(me.0, opponent)))
+            }
+            Strategy::Outcome => {
+                let outcome = Outcome::from(self.column);
+                let me = game
+                    .shapes_for(opponent, outcome)
+                    .exactly_one()
+                    .map_err(|_| {
+                        let message =
+                            format!("no single shape gives a {outcome:?} against {opponent:?}");
+                        Error::solve(2, message)
+                    })?;
+                Ok((MyShape(me), outcome))
+            }
+        }
+    }
+}
+
+/// One played round of the game log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggedRound {
+    /// Round number, counting from 1.
+    pub round: usize,
+    pub opponent: Shape,
+    pub me: Shape,
+    pub outcome: Outcome,
+    pub score: i32,
+    /// Total score of this and every earlier round.
+    pub total: i32,
+}
+
+/// Every round played when following the strategy guide.
+#[derive(Debug)]
+pub struct GameLog {
+    pub rounds: Vec<LoggedRound>,
+}
+
+impl GameLog {
+    pub fn new(rounds: &[Round], strategy: Strategy) -> Result<Self> {
+        let game = game();
+        let mut log = vec![];
+        let mut total = 0;
+
+        for (i, round) in rounds.iter().enumerate() {
+            let (me, outcome) = round.play(&game, strategy)?;
+            let score = me.0 as i32 + outcome_score(outcome);
+
+            total += score;
+
+            debug!(
+                "{:?} against {:?} is a {:?} which has a score of {} + {} = {}. Current Total: {}",
+                me.0,
+                round.opponent.0,
+                outcome,
+                me.0 as i32,
+                outcome_score(outcome),
+                score,
+                total
+            );
+
+            log.push(LoggedRound {
+                round: i + 1,
+                opponent: round.opponent.0,
+                me: me.0,
+                outcome,
+                score,
+                total,
+            });
+        }
+
+        Ok(Self { rounds: log })
+    }
+
+    pub fn total(&self) -> i32 {
+        self.rounds.last().map_or(0, |round| round.total)
+    }
+
+    /// Number of rounds ending in the given outcome.
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.rounds.iter().filter(|r| r.outcome == outcome).count()
+    }
+
+    /// Points scored in the rounds where I played each shape.
+    pub fn score_by_shape(&self) -> [(Shape, i32); 3] {
+        [Shape::Rock, Shape::Paper, Shape::Scissors].map(|shape| {
+            let score = self
+                .rounds
+                .iter()
+                .filter(|r| r.me == shape)
+                .map(|r| r.score)
+                .sum();
+            (shape, score)
+        })
+    }
+
+    /// Most rounds won in a row.
+    pub fn longest_win_streak(&self) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+        for round in &self.rounds {
+            current = if round.outcome == Outcome::Win {
+                current + 1
+            } else {
+                0
+            };
+            longest = longest.max(current);
+        }
+        longest
+    }
+}
+
+impl Report for GameLog {
+    fn write_table(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(
+            w,
+            "{:>5} {:<8} {:<8} {:<7} {:>5} {:>7}",
+            "Round", "Opponent", "Me", "Outcome", "Score", "Total"
+        )?;
+        for r in &self.rounds {
+            writeln!(
+                w,
+                "{:>5} {:<8} {:<8} {:<7} {:>5} {:>7}",
+                r.round,
+                format!("{:?}", r.opponent),
+                format!("{:?}", r.me),
+                format!("{:?}", r.outcome),
+                r.score,
+                r.total
+            )?;
+        }
+
+        writeln!(w)?;
+        writeln!(w, "Rounds: {}", self.rounds.len())?;
+        for (label, outcome) in [
+            ("Wins:", Outcome::Win),
+            ("Losses:", Outcome::Loss),
+            ("Ties:", Outcome::Tie),
+        ] {
+            writeln!(w, "{label:<7} {}", self.count(outcome))?;
+        }
+        writeln!(w, "Longest win streak: {}", self.longest_win_streak())?;
+        for (shape, score) in self.score_by_shape() {
+            writeln!(w, "Score playing {:<9} {score}", format!("{shape:?}:"))?;
+        }
+        writeln!(w, "Total score: {}", self.total())?;
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "round,opponent,me,outcome,score,total")?;
+        for r in &self.rounds {
+            writeln!(
+                w,
+                "{},{:?},{:?},{:?},{},{}",
+                r.round, r.opponent, r.me, r.outcome, r.score, r.total
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Total score when following the strategy guide.
+pub fn total_score(rounds: &[Round], strategy: Strategy) -> Result<i32> {
+    Ok(GameLog::new(rounds, strategy)?.total())
+}
+
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<Round>> {
+    let mut rounds = vec![];
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let round = match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [opponent, column] => Round {
+                opponent: opponent.parse().map_err(|err| {
+                    Error::parse(2, i + 1, error::column(&line, opponent), &line, err)
+                })?,
+                column: column.parse().map_err(|_| {
+                    let message = format!("invalid strategy column: {column}");
+                    Error::parse(2, i + 1, error::column(&line, column), &line, message)
+                })?,
+            },
+            _ => {
+                let message = "expected an opponent shape and a strategy column";
+                return Err(Error::parse(2, i + 1, 1, &line, message));
+            }
+        };
+        rounds.push(round);
+    }
+
+    Ok(rounds)
+}
+
+/// Total score when the second column is the shape I should play.
+pub fn part1(rounds: &[Round]) -> Result<i32> {
+    total_score(rounds, Strategy::SHAPES)
+}
+
+/// Total score when the second column is the result I should end up with.
+pub fn part2(rounds: &[Round]) -> Result<i32> {
+    total_score(rounds, Strategy::Outcome)
+}
+
+/// Total score of every possible reading of the strategy guide, to audit the
+/// guide before trusting it.
+#[derive(Debug)]
+pub struct StrategyReport {
+    /// Every reading along with its total score, in the order of [`Strategy::all`].
+    pub scores: Vec<(Strategy, i32)>,
+}
+
+impl StrategyReport {
+    pub fn new(rounds: &[Round]) -> Result<Self> {
+        let scores = Strategy::all()
+            .into_iter()
+            .map(|strategy| Ok((strategy, total_score(rounds, strategy)?)))
+            .collect::<Result<_>>()?;
+        Ok(Self { scores })
+    }
+
+    /// The readings scoring the most points.
+    pub fn best(&self) -> Vec<Strategy> {
+        let max = self.scores.iter().map(|(_, score)| *score).max();
+        self.with_score(max)
+    }
+
+    /// The readings scoring the fewest points.
+    pub fn worst(&self) -> Vec<Strategy> {
+        let min = self.scores.iter().map(|(_, score)| *score).min();
+        self.with_score(min)
+    }
+
+    fn with_score(&self, score: Option<i32>) -> Vec<Strategy> {
+        self.scores
+            .iter()
+            .filter(|(_, s)| Some(*s) == score)
+            .map(|(strategy, _)| *strategy)
+            .collect()
+    }
+}
+
+impl Report for StrategyReport {
+    fn write_table(&self, w: &mut dyn Write) -> io::Result<()> {
+        let (best, worst) = (self.best(), self.worst());
+        writeln!(w, "{:<32} {:>8}", "Strategy", "Score")?;
+        for (strategy, score) in &self.scores {
+            let note = match strategy {
+                _ if *strategy == Strategy::SHAPES => "  (part 1)",
+                Strategy::Outcome => "  (part 2)",
+                _ => "",
+            };
+            writeln!(w, "{:<32} {score:>8}{note}", strategy.to_string())?;
+        }
+        writeln!(w)?;
+        for (label, strategies) in [("Best:", best), ("Worst:", worst)] {
+            for strategy in strategies {
+                writeln!(w, "{label:<7}{strategy}")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        let (best, worst) = (self.best(), self.worst());
+        writeln!(w, "reading,x,y,z,score,best,worst")?;
+        for (strategy, score) in &self.scores {
+            let (reading, [x, y, z]) = match strategy {
+                Strategy::Shapes(shapes) => ("shape", shapes.map(|s| format!("{s:?}"))),
+                Strategy::Outcome => ("outcome", ["Loss", "Tie", "Win"].map(String::from)),
+            };
+            writeln!(
+                w,
+                "{reading},{x},{y},{z},{score},{},{}",
+                best.contains(strategy),
+                worst.contains(strategy)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z
+";
+
+    #[test]
+    fn example_part1() {
+        let rounds = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&rounds).unwrap(), 15);
+    }
+
+    #[test]
+    fn example_part2() {
+        let rounds = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&rounds).unwrap(), 12);
+    }
+
+    #[test]
+    fn strategy_report() {
+        let rounds = parse(EXAMPLE.as_bytes()).unwrap();
+        let report = StrategyReport::new(&rounds).unwrap();
+        assert_eq!(report.scores.len(), 7);
+        assert!(report.scores.contains(&(Strategy::SHAPES, 15)));
+        assert!(report.scores.contains(&(Strategy::Outcome, 12)));
+        assert_eq!(
+            report.best(),
+            vec![Strategy::Shapes([
+                Shape::Scissors,
+                Shape::Paper,
+                Shape::Rock
+            ])]
+        );
+        assert_eq!(
+            report.worst(),
+            vec![Strategy::Shapes([
+                Shape::Rock,
+                Shape::Scissors,
+                Shape::Paper
+            ])]
+        );
+    }
+
+    #[test]
+    fn game_log() {
+        let rounds = parse(EXAMPLE.as_bytes()).unwrap();
+        let log = GameLog::new(&rounds, Strategy::SHAPES).unwrap();
+        let totals: Vec<i32> = log.rounds.iter().map(|r| r.total).collect();
+        assert_eq!(totals, vec![8, 9, 15]);
+        assert_eq!(log.count(Outcome::Win), 1);
+        assert_eq!(log.count(Outcome::Loss), 1);
+        assert_eq!(log.count(Outcome::Tie), 1);
+        assert_eq!(log.longest_win_streak(), 1);
+        assert_eq!(
+            log.score_by_shape(),
+            [(Shape::Rock, 1), (Shape::Paper, 8), (Shape::Scissors, 6)]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("A X\nB W\n".as_bytes()).err().unwrap();
+        let expected = "\
+day 02, line 2, column 3: invalid strategy column: W
+2 | B W
+  |   ^";
+        assert_eq!(err.to_string(), expected);
+    }
+}
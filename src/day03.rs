@@ -0,0 +1,575 @@
+//! Day 3: Rucksack Reorganization
+
+use clap::ValueEnum;
+use itertools::Itertools;
+use log::{debug, warn};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::BitAnd;
+
+use crate::report::Report;
+use crate::{Error, Result};
+
+/// Priority of an item: a-z are 1-26 and A-Z are 27-52.
+pub const fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item with the given priority, the inverse of [`priority`].
+pub const fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => Some((b'a' + (priority - 1) as u8) as char),
+        27..=52 => Some((b'A' + (priority - 27) as u8) as char),
+        _ => None,
+    }
+}
+
+/// A set of items stored as a bitmask, where bit `n` is set when the item
+/// with priority `n` is in the set. Intersecting sets is a single `&`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    pub const EMPTY: Self = Self(0);
+    /// Every item that has a priority.
+    pub const ALL: Self = Self(((1 << 53) - 1) & !1);
+
+    /// Adds the item, returning false if it has no priority and was left out.
+    pub fn insert(&mut self, item: char) -> bool {
+        match priority(item) {
+            Some(priority) => {
+                self.0 |= 1 << priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items in the set, in order of priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        (1..=52).filter_map(move |p| {
+            if self.0 & (1 << p) != 0 {
+                item(p)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The only item in the set, if it holds exactly one.
+    pub fn single(self) -> Option<char> {
+        if self.len() == 1 {
+            item(self.0.trailing_zeros())
+        } else {
+            None
+        }
+    }
+
+    /// Sum of the priorities of the items in the set.
+    pub fn priority(self) -> u32 {
+        (1..=52).filter(|p| self.0 & (1 << p) != 0).sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+/// Collects the items into a set, leaving out any item without a priority.
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+/// The items found in every one of the sets.
+pub fn common_items(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+    sets.into_iter().fold(ItemSet::ALL, BitAnd::bitand)
+}
+
+/// A rucksack holds its items split evenly across two compartments.
+#[derive(Debug)]
+pub struct Rucksack {
+    /// 1-based line of the input the rucksack was read from.
+    pub line: usize,
+    pub items: Vec<char>,
+}
+
+impl Rucksack {
+    pub fn compartments(&self) -> (&[char], &[char]) {
+        let middle = self.items.len() / 2;
+        (&self.items[..middle], &self.items[middle..])
+    }
+
+    pub fn item_set(&self) -> ItemSet {
+        self.items.iter().copied().collect()
+    }
+
+    /// The items in both compartments.
+    pub fn shared_items(&self) -> ItemSet {
+        let (first, second) = self.compartments();
+        common_items([first, second].map(|items| items.iter().copied().collect()))
+    }
+}
+
+/// What is wrong with a malformed rucksack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// An odd number of items cannot be split evenly across the compartments.
+    OddLength(usize),
+    /// An item without a priority, at the given 1-based column.
+    InvalidItem { column: usize, item: char },
+    /// The compartments do not share exactly one item, but the given items.
+    NoCommonItem(Vec<char>),
+}
+
+impl Problem {
+    /// Whether the problem gets in the way of finding badges, which only
+    /// looks at the items and not at the compartments.
+    pub fn affects_badges(&self) -> bool {
+        matches!(self, Self::InvalidItem { .. })
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength(len) => {
+                write!(f, "odd number of items ({len}), cannot split into halves")
+            }
+            Self::InvalidItem { item, .. } => write!(f, "invalid item: {item:?}"),
+            Self::NoCommonItem(items) => write!(
+                f,
+                "expected exactly one item in both compartments but found {items:?}"
+            ),
+        }
+    }
+}
+
+/// A problem found with the rucksack on the given line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Malformed {
+    pub line: usize,
+    pub text: String,
+    pub problem: Problem,
+}
+
+impl From<&Malformed> for Error {
+    fn from(malformed: &Malformed) -> Self {
+        let column = match malformed.problem {
+            Problem::OddLength(len) => len,
+            Problem::InvalidItem { column, .. } => column,
+            Problem::NoCommonItem(_) => 1,
+        };
+        let message = malformed.problem.to_string();
+        Error::parse(3, malformed.line, column, &malformed.text, message)
+    }
+}
+
+/// How to treat malformed rucksacks while solving.
+#[derive(ValueEnum, Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Validation {
+    /// Fail on the first malformed rucksack.
+    #[default]
+    Strict,
+    /// Warn about malformed rucksacks and carry on: invalid items are
+    /// ignored, while rucksacks and groups that cannot be solved are skipped.
+    Lenient,
+    /// Lenient, without warning about each malformed rucksack, for callers
+    /// that have already reported them through [`validate`].
+    #[value(skip)]
+    Quiet,
+}
+
+impl Validation {
+    /// Fails under strict validation, and warns under lenient validation.
+    fn check(self, malformed: &Malformed) -> Result<()> {
+        match self {
+            Self::Strict => Err(malformed.into()),
+            Self::Lenient => {
+                warn!("line {}: {}", malformed.line, malformed.problem);
+                Ok(())
+            }
+            Self::Quiet => Ok(()),
+        }
+    }
+}
+
+impl Rucksack {
+    /// Every problem with the rucksack, in order of appearance.
+    pub fn problems(&self) -> Vec<Malformed> {
+        let mut problems = vec![];
+        if !self.items.len().is_multiple_of(2) {
+            problems.push(Problem::OddLength(self.items.len()));
+        }
+        for (i, &item) in self.items.iter().enumerate() {
+            if priority(item).is_none() {
+                problems.push(Problem::InvalidItem {
+                    column: i + 1,
+                    item,
+                });
+            }
+        }
+        if self.items.len().is_multiple_of(2) {
+            let shared = self.shared_items();
+            if shared.single().is_none() {
+                problems.push(Problem::NoCommonItem(shared.items().collect()));
+            }
+        }
+
+        problems
+            .into_iter()
+            .map(|problem| Malformed {
+                line: self.line,
+                text: self.items.iter().collect(),
+                problem,
+            })
+            .collect()
+    }
+}
+
+/// Every problem with every rucksack, rather than stopping at the first.
+pub fn validate(rucksacks: &[Rucksack]) -> Vec<Malformed> {
+    rucksacks.iter().flat_map(Rucksack::problems).collect()
+}
+
+/// Reads one rucksack per line. Malformed rucksacks are left for [`validate`]
+/// or the solvers to report, since the badges can be found even in rucksacks
+/// that cannot be split into compartments.
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<Rucksack>> {
+    let mut rucksacks = vec![];
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        rucksacks.push(Rucksack {
+            line: i + 1,
+            items: line.chars().collect(),
+        });
+    }
+
+    Ok(rucksacks)
+}
+
+/// Sum of the priorities of the item found in both compartments of each
+/// rucksack, treating malformed rucksacks as the validation says.
+pub fn compartment_total(rucksacks: &[Rucksack], validation: Validation) -> Result<u32> {
+    let mut total = 0;
+
+    for rucksack in rucksacks {
+        let mut skip = false;
+        for malformed in rucksack.problems() {
+            validation.check(&malformed)?;
+            skip |= !matches!(malformed.problem, Problem::InvalidItem { .. });
+        }
+        if !skip {
+            total += rucksack.shared_items().priority();
+        }
+    }
+
+    Ok(total)
+}
+
+/// Sum of the priorities of the item found in both compartments of each rucksack.
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32> {
+    compartment_total(rucksacks, Validation::Strict)
+}
+
+/// The rucksacks of a group of elves along with the items they all carry.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Group {
+    /// Lines the rucksacks of the group were read from.
+    pub lines: Vec<usize>,
+    pub shared: ItemSet,
+}
+
+impl Group {
+    /// Why the group has no badge, when it does not share exactly one item or
+    /// is short of elves.
+    pub fn problem(&self, group_size: usize) -> Option<String> {
+        if self.lines.len() < group_size {
+            Some(format!(
+                "only {} of {group_size} rucksacks in the group",
+                self.lines.len()
+            ))
+        } else if self.shared.is_empty() {
+            Some("no item shared by the group".to_string())
+        } else if self.shared.len() > 1 {
+            Some(format!("{} items shared by the group", self.shared.len()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Splits the rucksacks into groups of `group_size` consecutive elves. The
+/// last group is short when the rucksacks do not split evenly.
+pub fn groups(rucksacks: &[Rucksack], group_size: usize) -> Result<Vec<Group>> {
+    if group_size == 0 {
+        return Err(Error::solve(3, "groups need at least one elf"));
+    }
+
+    let groups = rucksacks
+        .chunks(group_size)
+        .map(|group| Group {
+            lines: group.iter().map(|rucksack| rucksack.line).collect(),
+            shared: common_items(group.iter().map(Rucksack::item_set)),
+        })
+        .collect();
+    Ok(groups)
+}
+
+/// Sum of the priorities of the badge shared by each group of elves,
+/// treating malformed rucksacks and groups as the validation says.
+pub fn badge_total(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+    validation: Validation,
+) -> Result<u32> {
+    for malformed in validate(rucksacks) {
+        if malformed.problem.affects_badges() {
+            validation.check(&malformed)?;
+        }
+    }
+
+    let mut total = 0;
+
+    for group in groups(rucksacks, group_size)? {
+        if let Some(problem) = group.problem(group_size) {
+            let message = format!(
+                "expected exactly one item shared by the group starting on line {} but found {:?} ({problem})",
+                group.lines[0],
+                group.shared.items().collect::<Vec<char>>()
+            );
+            match validation {
+                Validation::Strict => return Err(Error::solve(3, message)),
+                Validation::Lenient | Validation::Quiet => {
+                    warn!("{message}");
+                    continue;
+                }
+            }
+        }
+        debug!(
+            "group starting on line {} has badge {}",
+            group.lines[0],
+            group.shared.items().join("")
+        );
+        total += group.shared.priority();
+    }
+
+    Ok(total)
+}
+
+/// Sum of the priorities of the badge shared by each group of three elves.
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u32> {
+    badge_total(rucksacks, 3, Validation::Strict)
+}
+
+/// Every group of elves with the items they share, flagging the groups
+/// without exactly one badge.
+#[derive(Debug)]
+pub struct GroupReport {
+    pub group_size: usize,
+    pub groups: Vec<Group>,
+}
+
+impl GroupReport {
+    pub fn new(rucksacks: &[Rucksack], group_size: usize) -> Result<Self> {
+        Ok(Self {
+            group_size,
+            groups: groups(rucksacks, group_size)?,
+        })
+    }
+
+    /// Number of groups without exactly one badge.
+    pub fn problems(&self) -> usize {
+        self.groups
+            .iter()
+            .filter(|group| group.problem(self.group_size).is_some())
+            .count()
+    }
+}
+
+impl Report for GroupReport {
+    fn write_table(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "{:>5}  {:<16} {:<8} Problem", "Group", "Lines", "Shared")?;
+        for (i, group) in self.groups.iter().enumerate() {
+            let row = format!(
+                "{:>5}  {:<16} {:<8} {}",
+                i + 1,
+                group.lines.iter().join(", "),
+                group.shared.items().join(""),
+                group.problem(self.group_size).unwrap_or_default()
+            );
+            writeln!(w, "{}", row.trim_end())?;
+        }
+        writeln!(w)?;
+        writeln!(
+            w,
+            "{} groups of {}, {} without exactly one badge",
+            self.groups.len(),
+            self.group_size,
+            self.problems()
+        )?;
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "group,lines,shared,badge,problem")?;
+        for (i, group) in self.groups.iter().enumerate() {
+            let problem = group.problem(self.group_size);
+            let badge = match problem {
+                None => group.shared.items().join(""),
+                Some(_) => String::new(),
+            };
+            writeln!(
+                w,
+                "{},{},{},{badge},{}",
+                i + 1,
+                group.lines.iter().join(";"),
+                group.shared.items().join(""),
+                problem.unwrap_or_default()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn priorities() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('z'), Some(26));
+        assert_eq!(priority('A'), Some(27));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('1'), None);
+        assert!((1..=52).all(|p| item(p).and_then(priority) == Some(p)));
+    }
+
+    #[test]
+    fn item_sets() {
+        let first: ItemSet = "vJrwpWtwJgWr".chars().collect();
+        let second: ItemSet = "hcsFMMfFFhFp".chars().collect();
+        assert_eq!((first & second).single(), Some('p'));
+
+        let set: ItemSet = "bAa".chars().collect();
+        assert_eq!(set.items().collect::<String>(), "abA");
+        assert_eq!(set.priority(), 1 + 2 + 27);
+        assert_eq!(common_items([set, ItemSet::ALL]), set);
+    }
+
+    #[test]
+    fn example_part1() {
+        let rucksacks = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&rucksacks).unwrap(), 157);
+    }
+
+    #[test]
+    fn example_part2() {
+        let rucksacks = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&rucksacks).unwrap(), 70);
+    }
+
+    #[test]
+    fn group_report() {
+        let rucksacks = parse(EXAMPLE.as_bytes()).unwrap();
+        assert!(badge_total(&rucksacks, 6, Validation::Strict).is_err());
+
+        let report = GroupReport::new(&rucksacks, 2).unwrap();
+        let shared: Vec<String> = report
+            .groups
+            .iter()
+            .map(|group| group.shared.items().collect())
+            .collect();
+        assert_eq!(shared, vec!["frsFM", "qvwBT", "GJZ"]);
+        assert_eq!(report.groups[2].lines, vec![5, 6]);
+        assert_eq!(report.problems(), 3);
+
+        let report = GroupReport::new(&rucksacks, 3).unwrap();
+        assert_eq!(report.problems(), 0);
+
+        let report = GroupReport::new(&rucksacks, 4).unwrap();
+        assert_eq!(
+            report.groups[1].problem(4).unwrap(),
+            "only 2 of 4 rucksacks in the group"
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\nabcdefg\na1bca2\nabcdef\n";
+        let rucksacks = parse(input.as_bytes()).unwrap();
+        let problems: Vec<(usize, String)> = validate(&rucksacks)
+            .into_iter()
+            .map(|malformed| (malformed.line, malformed.problem.to_string()))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    2,
+                    "odd number of items (7), cannot split into halves".into()
+                ),
+                (3, "invalid item: '1'".into()),
+                (3, "invalid item: '2'".into()),
+                (
+                    4,
+                    "expected exactly one item in both compartments but found []".into()
+                ),
+            ]
+        );
+
+        assert!(part1(&rucksacks).is_err());
+        // the third rucksack is counted with its invalid items ignored
+        let total = compartment_total(&rucksacks, Validation::Lenient).unwrap();
+        assert_eq!(total, 16 + 1);
+        let quiet = compartment_total(&rucksacks, Validation::Quiet).unwrap();
+        assert_eq!(quiet, total);
+    }
+
+    #[test]
+    fn badges_do_not_need_compartments() {
+        let input = "abc\nbcdef\nb\n";
+        let rucksacks = parse(input.as_bytes()).unwrap();
+        assert!(part1(&rucksacks).is_err());
+        assert_eq!(part2(&rucksacks).unwrap(), 2);
+    }
+}
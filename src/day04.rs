@@ -0,0 +1,304 @@
+//! Day 4: Camp Cleanup
+
+use itertools::Itertools;
+use log::debug;
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use crate::error;
+use crate::interval::{IntervalSet, SectionRange};
+use crate::report::Report;
+use crate::{Error, Result};
+
+/// The section assignments of a pair of elves.
+#[derive(Debug)]
+pub struct Pair {
+    /// 1-based line of the input the pair was read from.
+    pub line: usize,
+    pub first: SectionRange,
+    pub second: SectionRange,
+}
+
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<Pair>> {
+    let mut pairs = vec![];
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let ranges = line
+            .split(',')
+            .map(|range| {
+                range
+                    .parse::<SectionRange>()
+                    .map_err(|err| Error::parse(4, i + 1, error::column(&line, range), &line, err))
+            })
+            .collect::<Result<Vec<SectionRange>>>()?;
+        let [first, second] = ranges[..] else {
+            let message = "expected two comma separated ranges";
+            return Err(Error::parse(4, i + 1, 1, &line, message));
+        };
+
+        pairs.push(Pair {
+            line: i + 1,
+            first,
+            second,
+        });
+    }
+
+    Ok(pairs)
+}
+
+/// Number of pairs where one range fully contains the other.
+pub fn part1(pairs: &[Pair]) -> Result<u32> {
+    let count = pairs
+        .iter()
+        .filter(|pair| pair.first.contains(&pair.second) || pair.second.contains(&pair.first))
+        .count();
+
+    Ok(count as u32)
+}
+
+/// Number of pairs whose ranges overlap at all.
+pub fn part2(pairs: &[Pair]) -> Result<u32> {
+    let mut count = 0;
+
+    for Pair { first, second, .. } in pairs {
+        let overlaps = first.overlaps(second);
+
+        if overlaps {
+            count += 1
+        }
+
+        debug!("{first},{second} == {}", overlaps);
+    }
+
+    Ok(count)
+}
+
+/// The sections assigned to one of the elves of a pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub line: usize,
+    /// 1 for the first elf of the pair and 2 for the second.
+    pub elf: u8,
+    pub range: SectionRange,
+}
+
+/// How the assignments of every elf cover the sections of the camp.
+#[derive(Debug)]
+pub struct CoverageReport {
+    pub camp: SectionRange,
+    pub assignments: usize,
+    /// Consecutive sections of the camp along with how many elves cover them.
+    pub depths: Vec<(SectionRange, usize)>,
+    pub uncovered: IntervalSet,
+    pub multiply_covered: IntervalSet,
+    pub max_depth: usize,
+    /// The sections covered by `max_depth` elves.
+    pub deepest: IntervalSet,
+    /// Assignments whose every section is also covered by another elf. Note
+    /// that identical assignments are each redundant but cannot all be dropped.
+    pub redundant: Vec<Assignment>,
+}
+
+impl CoverageReport {
+    /// Analyses the coverage of the camp, which defaults to the sections
+    /// from the lowest to the highest assigned.
+    pub fn new(pairs: &[Pair], camp: Option<SectionRange>) -> Result<Self> {
+        let assignments: Vec<Assignment> = pairs
+            .iter()
+            .flat_map(|pair| {
+                [(1, pair.first), (2, pair.second)].map(|(elf, range)| Assignment {
+                    line: pair.line,
+                    elf,
+                    range,
+                })
+            })
+            .collect();
+
+        let camp = match camp {
+            Some(camp) => camp,
+            None => {
+                let start = assignments.iter().map(|a| a.range.start()).min();
+                let end = assignments.iter().map(|a| a.range.end()).max();
+                start
+                    .zip(end)
+                    .and_then(|(start, end)| SectionRange::new(start, end))
+                    .ok_or(Error::EmptyInput { day: 4 })?
+            }
+        };
+
+        // sweep line: every assignment adds an elf where it starts and
+        // removes it right after it ends, and so do the camp's edges so that
+        // the sweep is split where the camp starts and ends
+        let mut deltas = BTreeMap::<u64, i64>::new();
+        for assignment in &assignments {
+            *deltas.entry(assignment.range.start() as u64).or_default() += 1;
+            *deltas.entry(assignment.range.end() as u64 + 1).or_default() -= 1;
+        }
+        deltas.entry(camp.start() as u64).or_default();
+        deltas.entry(camp.end() as u64 + 1).or_default();
+
+        let mut pieces: Vec<(SectionRange, usize)> = vec![];
+        let mut depth = 0;
+        for ((&start, &delta), (&next, _)) in deltas.iter().tuple_windows() {
+            depth += delta;
+            if let Some(range) = SectionRange::new(start as u32, (next - 1) as u32) {
+                pieces.push((range, depth as usize));
+            }
+        }
+
+        let mut depths: Vec<(SectionRange, usize)> = vec![];
+        for &(range, depth) in pieces.iter().filter(|(range, _)| camp.contains(range)) {
+            match depths.last_mut() {
+                Some((last, d)) if *d == depth => {
+                    *last = SectionRange::new(last.start(), range.end()).unwrap_or(*last);
+                }
+                _ => depths.push((range, depth)),
+            }
+        }
+
+        let covered_by = |filter: &dyn Fn(usize) -> bool| -> IntervalSet {
+            depths
+                .iter()
+                .filter(|(_, depth)| filter(*depth))
+                .map(|(range, _)| *range)
+                .collect()
+        };
+        let max_depth = depths.iter().map(|(_, depth)| *depth).max().unwrap_or(0);
+        let uncovered = covered_by(&|depth| depth == 0);
+        let multiply_covered = covered_by(&|depth| depth >= 2);
+        let deepest = covered_by(&|depth| depth == max_depth && depth > 0);
+
+        // assignments may reach outside the camp, so tell whether they are
+        // redundant from the whole sweep rather than the part in the camp
+        let covered_twice: IntervalSet = pieces
+            .iter()
+            .filter(|(_, depth)| *depth >= 2)
+            .map(|(range, _)| *range)
+            .collect();
+        let redundant = assignments
+            .iter()
+            .filter(|a| {
+                IntervalSet::from_iter([a.range])
+                    .difference(&covered_twice)
+                    .is_empty()
+            })
+            .cloned()
+            .collect();
+
+        Ok(Self {
+            camp,
+            assignments: assignments.len(),
+            depths,
+            uncovered,
+            multiply_covered,
+            max_depth,
+            deepest,
+            redundant,
+        })
+    }
+}
+
+impl Report for CoverageReport {
+    fn write_table(&self, w: &mut dyn Write) -> io::Result<()> {
+        let or_none = |set: &IntervalSet| {
+            if set.is_empty() {
+                "none".to_string()
+            } else {
+                format!("{set} ({} sections)", set.size())
+            }
+        };
+        writeln!(w, "Camp:             {}", self.camp)?;
+        writeln!(w, "Assignments:      {}", self.assignments)?;
+        writeln!(w, "Uncovered:        {}", or_none(&self.uncovered))?;
+        writeln!(w, "Multiply covered: {}", or_none(&self.multiply_covered))?;
+        writeln!(
+            w,
+            "Max depth:        {} at {}",
+            self.max_depth,
+            or_none(&self.deepest)
+        )?;
+
+        writeln!(w, "\nRedundant assignments: {}", self.redundant.len())?;
+        for a in &self.redundant {
+            writeln!(w, "  line {:>4}, elf {}: {}", a.line, a.elf, a.range)?;
+        }
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "start,end,depth")?;
+        for (range, depth) in &self.depths {
+            writeln!(w, "{},{},{depth}", range.start(), range.end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn example_part1() {
+        let pairs = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&pairs).unwrap(), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        let pairs = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&pairs).unwrap(), 4);
+    }
+
+    #[test]
+    fn coverage_report() {
+        let pairs = parse(EXAMPLE.as_bytes()).unwrap();
+        let report = CoverageReport::new(&pairs, None).unwrap();
+        assert_eq!(report.camp.to_string(), "2-9");
+        assert!(report.uncovered.is_empty());
+        assert_eq!(report.multiply_covered.to_string(), "2-8");
+        assert_eq!(report.max_depth, 8);
+        assert_eq!(report.deepest.to_string(), "6-6");
+        // section 9 is only covered by the 7-9 on line 3, every other
+        // section by at least two elves
+        let redundant: Vec<(usize, u8)> =
+            report.redundant.iter().map(|a| (a.line, a.elf)).collect();
+        assert_eq!(redundant.len(), 11);
+        assert!(!redundant.contains(&(3, 2)));
+
+        let camp = "1-12".parse().ok();
+        let report = CoverageReport::new(&pairs, camp).unwrap();
+        assert_eq!(report.uncovered.to_string(), "1-1,10-12");
+    }
+
+    #[test]
+    fn redundant_beyond_the_camp() {
+        let pairs = parse("1-5,1-5\n".as_bytes()).unwrap();
+        let report = CoverageReport::new(&pairs, "1-3".parse().ok()).unwrap();
+        assert_eq!(report.multiply_covered.to_string(), "1-3");
+        assert_eq!(report.redundant.len(), 2);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("2-4,6-8\n2-4\n".as_bytes()).err().unwrap();
+        let expected = "\
+day 04, line 2, column 1: expected two comma separated ranges
+2 | 2-4
+  | ^";
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -0,0 +1,733 @@
+//! Day 5: Supply Stacks
+
+use itertools::Itertools;
+use log::debug;
+use parse_display::{Display, FromStr};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::BufRead;
+use std::num::NonZeroU32;
+use std::ops::RangeInclusive;
+
+use crate::error;
+use crate::paragraphs::{paragraphs, Paragraph};
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack<T> {
+    data: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self {
+            data: Vec::<T>::new(),
+        }
+    }
+
+    pub fn push(&mut self, c: T) {
+        self.data.push(c);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The item at the given height, counting from 0 at the bottom.
+    pub fn get(&self, height: usize) -> Option<&T> {
+        self.data.get(height)
+    }
+
+    /// Removes the top `n` items, keeping their order from bottom to top, or
+    /// leaves the stack alone if it holds fewer than `n` items.
+    pub fn take(&mut self, n: usize) -> Option<Vec<T>> {
+        let height = self.data.len().checked_sub(n)?;
+        Some(self.data.split_off(height))
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A crate, drawn as its label in brackets: `[A]`.
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+pub struct Crate(pub String);
+
+/// Ship has multiple stacks of crates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ship {
+    pub stacks: BTreeMap<u32, Stack<Crate>>,
+}
+
+impl Ship {
+    /// The labels of the crates on top of each stack, from left to right.
+    pub fn top_crates(&self) -> Result<String> {
+        let mut output = String::new();
+        for (num, stack) in &self.stacks {
+            let krate = stack.peek().ok_or_else(|| empty_stack(*num))?;
+            output.push_str(&krate.0);
+        }
+        Ok(output)
+    }
+
+    /// Checks the instruction can be carried out on the ship as it is.
+    pub fn check(&self, instruction: &MoveInstruction) -> std::result::Result<(), MoveProblem> {
+        check_move(instruction, |num| self.stacks.get(&num).map(Stack::len))
+    }
+
+    fn stack_mut(&mut self, num: u32) -> std::result::Result<&mut Stack<Crate>, MoveProblem> {
+        self.stacks
+            .get_mut(&num)
+            .ok_or(MoveProblem::MissingStack(num))
+    }
+}
+
+const RED: &str = "\x1b[1;31m";
+const GREEN: &str = "\x1b[1;32m";
+const RESET: &str = "\x1b[0m";
+
+/// What to pick out when drawing the ship after a move: the stacks moved
+/// from and to, and the crates moved on top of the destination stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight {
+    pub from: u32,
+    pub to: u32,
+    pub moved: u32,
+}
+
+impl From<&MoveInstruction> for Highlight {
+    fn from(instruction: &MoveInstruction) -> Self {
+        Self {
+            from: instruction.from,
+            to: instruction.to,
+            moved: instruction.num,
+        }
+    }
+}
+
+impl Ship {
+    /// Draws the ship like its `Display` does, coloured for a terminal to
+    /// show the source stack in red and the destination stack and the
+    /// crates moved onto it in green.
+    pub fn draw_highlighted(&self, highlight: &Highlight) -> String {
+        self.draw(Some(highlight))
+    }
+
+    fn draw(&self, highlight: Option<&Highlight>) -> String {
+        let colour = |num: u32| match highlight {
+            Some(h) if num == h.from => Some(RED),
+            Some(h) if num == h.to => Some(GREEN),
+            _ => None,
+        };
+        let paint = |text: String, colour: Option<&str>| match colour {
+            Some(colour) => format!("{colour}{text}{RESET}"),
+            None => text,
+        };
+
+        // every column is as wide as the widest crate or stack number, with
+        // both centred in it the way `{:^width$}` would
+        let crates = self.stacks.values().flat_map(|stack| &stack.data);
+        let labels = crates.map(|krate| krate.0.chars().count() + 2);
+        let numbers = self.stacks.keys().map(|num| num.to_string().len());
+        let width = labels.chain(numbers).max().unwrap_or(0).max(3);
+        let centre = |text: String, colour: Option<&str>| {
+            let padding = width - text.chars().count();
+            let (left, right) = (padding / 2, padding - padding / 2);
+            format!(
+                "{}{}{}",
+                " ".repeat(left),
+                paint(text, colour),
+                " ".repeat(right)
+            )
+        };
+
+        let mut output = String::new();
+        let height = self.stacks.values().map(Stack::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self
+                .stacks
+                .iter()
+                .map(|(&num, stack)| match stack.get(level) {
+                    Some(krate) => {
+                        let moved = highlight.is_some_and(|h| {
+                            num == h.to && level + h.moved as usize >= stack.len()
+                        });
+                        centre(format!("[{krate}]"), moved.then_some(GREEN))
+                    }
+                    None => " ".repeat(width),
+                });
+            output += &format!("{}\n", row.format(" "));
+        }
+        let footer = self
+            .stacks
+            .keys()
+            .map(|&num| paint(format!("{num:^width$}"), colour(num)));
+        output += &format!("{}\n", footer.format(" "));
+        output
+    }
+}
+
+/// Draws the ship the way the puzzle input does, one `[X]` column per stack
+/// with the line numbering the stacks at the bottom. Columns widen to fit
+/// the widest label or stack number.
+impl fmt::Display for Ship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.draw(None))
+    }
+}
+
+/// Columns of the line spanned by `token`, a subslice of `line`, counting
+/// chars from 0 so that labels beyond ASCII line up with the stack numbers.
+fn span(line: &str, token: &str) -> RangeInclusive<usize> {
+    let start = line[..error::column(line, token) - 1].chars().count();
+    start..=start + token.chars().count() - 1
+}
+
+/// Parses the ship drawing, whose last line numbers the stacks. Each crate
+/// belongs to the stack whose number it sits above, so stacks may be
+/// numbered with several digits and crates may have labels of any width,
+/// and lines need not be padded with trailing whitespace.
+impl TryFrom<&Paragraph> for Ship {
+    type Error = Error;
+
+    fn try_from(drawing: &Paragraph) -> Result<Ship> {
+        let mut ship = Self {
+            stacks: BTreeMap::new(),
+        };
+
+        let lines: Vec<(usize, &str)> = drawing.numbered().collect();
+        let (&(footer, last), remaining) =
+            lines.split_last().ok_or(Error::EmptyInput { day: 5 })?;
+        if !last.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            let message = "missing the line numbering the stacks of the ship drawing";
+            return Err(Error::parse(5, footer, 1, last, message));
+        }
+
+        let mut columns = vec![];
+        for x in last.split_ascii_whitespace() {
+            let invalid =
+                |message: String| Error::parse(5, footer, error::column(last, x), last, message);
+            let num = x
+                .parse::<u32>()
+                .map_err(|err| invalid(format!("invalid stack number {x:?}: {err}")))?;
+            if ship.stacks.insert(num, Stack::new()).is_some() {
+                return Err(invalid(format!("stack {num} is numbered more than once")));
+            }
+            columns.push((span(last, x), num));
+        }
+
+        for &(i, line) in remaining.iter().rev() {
+            let mut filled = vec![];
+            for x in line.split_ascii_whitespace() {
+                let invalid = |message| Error::parse(5, i, error::column(line, x), line, message);
+                let label = x
+                    .strip_prefix('[')
+                    .and_then(|x| x.strip_suffix(']'))
+                    .filter(|label| !label.is_empty())
+                    .ok_or_else(|| invalid("expected a crate such as [A]"))?;
+                let crate_span = span(line, x);
+                let mut above = columns.iter().filter(|(column, _)| {
+                    column.start() <= crate_span.end() && crate_span.start() <= column.end()
+                });
+                let (Some((_, num)), None) = (above.next(), above.next()) else {
+                    return Err(invalid(
+                        "expected the crate to sit above a single stack number",
+                    ));
+                };
+                if filled.contains(num) {
+                    return Err(invalid("expected a single crate per stack on each line"));
+                }
+                filled.push(*num);
+                let stack = ship.stacks.entry(*num).or_default();
+                stack.push(Crate(label.to_string()));
+            }
+        }
+
+        Ok(ship)
+    }
+}
+
+#[derive(Display, FromStr, PartialEq, Eq, Debug, Clone, Copy)]
+#[display("move {num} from {from} to {to}")]
+pub struct MoveInstruction {
+    pub num: u32,
+    pub from: u32,
+    pub to: u32,
+}
+
+/// Why a move instruction cannot be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveProblem {
+    /// The ship has no stack with the given number.
+    MissingStack(u32),
+    /// The stack holds fewer crates than the instruction moves.
+    NotEnoughCrates { stack: u32, height: usize, num: u32 },
+}
+
+impl fmt::Display for MoveProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingStack(num) => write!(f, "there is no stack {num}"),
+            Self::NotEnoughCrates { stack, height, num } => write!(
+                f,
+                "stack {stack} has height {height}, cannot move {num} crates from it"
+            ),
+        }
+    }
+}
+
+/// A problem with the instruction at the given index of the procedure,
+/// counting from 0, although the error message counts from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMove {
+    pub index: usize,
+    pub instruction: MoveInstruction,
+    pub problem: MoveProblem,
+}
+
+impl From<InvalidMove> for Error {
+    fn from(invalid: InvalidMove) -> Self {
+        let InvalidMove {
+            index,
+            instruction,
+            problem,
+        } = invalid;
+        Error::solve(
+            5,
+            format!("instruction {} ({instruction}): {problem}", index + 1),
+        )
+    }
+}
+
+/// Checks the instruction against the heights of the stacks, `None` meaning
+/// there is no such stack.
+fn check_move(
+    instruction: &MoveInstruction,
+    height: impl Fn(u32) -> Option<usize>,
+) -> std::result::Result<(), MoveProblem> {
+    let from = height(instruction.from).ok_or(MoveProblem::MissingStack(instruction.from))?;
+    height(instruction.to).ok_or(MoveProblem::MissingStack(instruction.to))?;
+    if from < instruction.num as usize {
+        return Err(MoveProblem::NotEnoughCrates {
+            stack: instruction.from,
+            height: from,
+            num: instruction.num,
+        });
+    }
+    Ok(())
+}
+
+/// The starting ship along with the rearrangement procedure to apply to it.
+pub struct Procedure {
+    pub ship: Ship,
+    pub instructions: Vec<MoveInstruction>,
+}
+
+pub fn parse<R: BufRead>(reader: R) -> Result<Procedure> {
+    let mut paragraphs = paragraphs(reader);
+
+    // the first paragraph is the drawing of the ship (stacks of crates)
+    let drawing = paragraphs.next().ok_or(Error::EmptyInput { day: 5 })??;
+    let ship = Ship::try_from(&drawing)?;
+
+    // the remaining lines are the move instructions
+    let mut instructions = vec![];
+    for paragraph in paragraphs {
+        let paragraph = paragraph?;
+        for (i, line) in paragraph.numbered() {
+            let instruction = line.parse().map_err(|err| {
+                let message = format!("invalid move instruction: {err}");
+                Error::parse(5, i, 1, line, message)
+            })?;
+            instructions.push(instruction);
+        }
+    }
+
+    Ok(Procedure { ship, instructions })
+}
+
+/// A crane carrying out the rearrangement procedure on a ship.
+pub trait Crane {
+    /// Most crates the crane lifts at once, or `None` when it can lift any
+    /// number of them. Crates lifted together keep their order.
+    fn capacity(&self) -> Option<NonZeroU32>;
+
+    /// Moves crates from one stack to another as the instruction says, in as
+    /// many lifts as the capacity of the crane requires. The ship is left
+    /// untouched if the instruction cannot be carried out.
+    fn execute(
+        &self,
+        ship: &mut Ship,
+        instruction: &MoveInstruction,
+    ) -> std::result::Result<(), MoveProblem> {
+        ship.check(instruction)?;
+        let mut remaining = instruction.num;
+        while remaining > 0 {
+            let lift = self
+                .capacity()
+                .map_or(remaining, |c| c.get().min(remaining));
+            let from = ship.stack_mut(instruction.from)?;
+            let krates = from
+                .take(lift as usize)
+                .ok_or(MoveProblem::NotEnoughCrates {
+                    stack: instruction.from,
+                    height: from.len(),
+                    num: lift,
+                })?;
+            ship.stack_mut(instruction.to)?.extend(krates);
+            remaining -= lift;
+        }
+        Ok(())
+    }
+}
+
+/// Moves crates one at a time, reversing the order of the crates it moves.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> Option<NonZeroU32> {
+        Some(NonZeroU32::MIN)
+    }
+}
+
+/// Moves several crates at once, keeping the order of the crates it moves.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> Option<NonZeroU32> {
+        None
+    }
+}
+
+/// Moves at most the given number of crates at once.
+pub struct CappedCrane(pub NonZeroU32);
+
+impl CappedCrane {
+    /// A crane lifting at most `capacity` crates, unless it can lift none.
+    pub fn new(capacity: u32) -> Option<Self> {
+        NonZeroU32::new(capacity).map(Self)
+    }
+}
+
+impl Crane for CappedCrane {
+    fn capacity(&self) -> Option<NonZeroU32> {
+        Some(self.0)
+    }
+}
+
+/// Called with the ship before the first move and after every move, along
+/// with the index of the instruction just carried out, counting from 0.
+pub type Observer<'a> = dyn FnMut(&Ship, Option<(usize, &MoveInstruction)>) + 'a;
+
+/// Top crates after carrying out the procedure with the given crane.
+pub fn rearrange(procedure: &Procedure, crane: &dyn Crane) -> Result<String> {
+    rearrange_with(procedure, crane, &mut |_, _| {})
+}
+
+/// Same as [`rearrange`], letting `observe` follow the ship move by move.
+pub fn rearrange_with(
+    procedure: &Procedure,
+    crane: &dyn Crane,
+    observe: &mut Observer<'_>,
+) -> Result<String> {
+    let mut ship = procedure.ship.clone();
+    debug!("ship:\n{ship}");
+    observe(&ship, None);
+
+    for (i, instruction) in procedure.instructions.iter().enumerate() {
+        crane
+            .execute(&mut ship, instruction)
+            .map_err(|problem| InvalidMove {
+                index: i,
+                instruction: *instruction,
+                problem,
+            })?;
+        observe(&ship, Some((i, instruction)));
+    }
+
+    debug!("ship:\n{ship}");
+
+    ship.top_crates()
+}
+
+/// The error for a stack left without a crate on top once the procedure is
+/// done, shared by [`Ship::top_crates`] and [`dry_run`] so that they agree.
+fn empty_stack(num: u32) -> Error {
+    Error::solve(5, format!("stack {num} is empty"))
+}
+
+/// Checks every instruction of the procedure can be carried out, whatever the
+/// crane, without moving any crates, and that no stack ends up empty, which
+/// would leave it without a top crate. Returns how many crates each stack
+/// holds once the procedure is done.
+pub fn dry_run(procedure: &Procedure) -> Result<BTreeMap<u32, usize>> {
+    let mut heights: BTreeMap<u32, usize> = procedure
+        .ship
+        .stacks
+        .iter()
+        .map(|(&num, stack)| (num, stack.len()))
+        .collect();
+
+    for (i, instruction) in procedure.instructions.iter().enumerate() {
+        check_move(instruction, |num| heights.get(&num).copied()).map_err(|problem| {
+            InvalidMove {
+                index: i,
+                instruction: *instruction,
+                problem,
+            }
+        })?;
+        let num = instruction.num as usize;
+        *heights.entry(instruction.from).or_default() -= num;
+        *heights.entry(instruction.to).or_default() += num;
+    }
+
+    if let Some((&num, _)) = heights.iter().find(|(_, &height)| height == 0) {
+        return Err(empty_stack(num));
+    }
+
+    Ok(heights)
+}
+
+/// Top crates after moving crates one at a time with the CrateMover 9000.
+pub fn part1(procedure: &Procedure) -> Result<String> {
+    rearrange(procedure, &CrateMover9000)
+}
+
+/// Top crates after moving crates several at once with the CrateMover 9001.
+pub fn part2(procedure: &Procedure) -> Result<String> {
+    rearrange(procedure, &CrateMover9001)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn example_part1() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&procedure).unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn example_part2() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&procedure).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn capped_crane() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        let capped = |capacity| CappedCrane::new(capacity).unwrap();
+        assert_eq!(rearrange(&procedure, &capped(1)).unwrap(), "CMZ");
+        assert_eq!(rearrange(&procedure, &capped(3)).unwrap(), "MCD");
+        // the second move lifts N and D together and then Z, leaving Z on top
+        assert_eq!(rearrange(&procedure, &capped(2)).unwrap(), "MCZ");
+        assert!(CappedCrane::new(0).is_none());
+
+        // four crates take two lifts, the top two crates being lifted first
+        let mut ship = procedure.ship.clone();
+        let instruction = "move 2 from 2 to 1".parse().unwrap();
+        capped(2).execute(&mut ship, &instruction).unwrap();
+        let instruction = "move 4 from 1 to 3".parse().unwrap();
+        capped(2).execute(&mut ship, &instruction).unwrap();
+        let labels: Vec<&str> = (0..5)
+            .filter_map(|height| ship.stacks[&3].get(height))
+            .map(|krate| krate.0.as_str())
+            .collect();
+        assert_eq!(labels, vec!["P", "C", "D", "Z", "N"]);
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let drawing = EXAMPLE.split("\n\n").next().unwrap();
+        let procedure = |moves: &str| parse(format!("{drawing}\n\n{moves}").as_bytes()).unwrap();
+
+        // the second move takes one crate too many from stack 1
+        let too_many = procedure("move 1 from 2 to 1\nmove 4 from 1 to 3\n");
+        let problem = MoveProblem::NotEnoughCrates {
+            stack: 1,
+            height: 3,
+            num: 4,
+        };
+        let mut ship = too_many.ship.clone();
+        CrateMover9000
+            .execute(&mut ship, &too_many.instructions[0])
+            .unwrap();
+        let before = ship.clone();
+        assert_eq!(
+            CrateMover9001.execute(&mut ship, &too_many.instructions[1]),
+            Err(problem.clone())
+        );
+        assert_eq!(ship, before);
+
+        let expected = Error::from(InvalidMove {
+            index: 1,
+            instruction: too_many.instructions[1],
+            problem,
+        });
+        assert_eq!(
+            part1(&too_many).unwrap_err().to_string(),
+            expected.to_string()
+        );
+        assert_eq!(
+            dry_run(&too_many).unwrap_err().to_string(),
+            expected.to_string()
+        );
+
+        let missing = procedure("move 1 from 2 to 4\n");
+        let err = part2(&missing).unwrap_err().to_string();
+        assert!(err.contains("instruction 1 (move 1 from 2 to 4): there is no stack 4"));
+    }
+
+    #[test]
+    fn dry_run_agrees_with_part1() {
+        // moving the only crate away leaves stack 1 without a top crate
+        let emptied = "[A]\n 1 2\n\nmove 1 from 1 to 2\n";
+        for input in [EXAMPLE, emptied] {
+            let procedure = parse(input.as_bytes()).unwrap();
+            let dry = dry_run(&procedure)
+                .map(|_| ())
+                .map_err(|err| err.to_string());
+            let real = part1(&procedure).map(|_| ()).map_err(|err| err.to_string());
+            assert_eq!(dry, real);
+        }
+        let procedure = parse(emptied.as_bytes()).unwrap();
+        assert_eq!(
+            dry_run(&procedure).unwrap_err().to_string(),
+            "day 05: stack 1 is empty"
+        );
+    }
+
+    #[test]
+    fn dry_run_leaves_ship_alone() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        let ship = procedure.ship.clone();
+        let heights = dry_run(&procedure).unwrap();
+        assert_eq!(heights, BTreeMap::from([(1, 1), (2, 1), (3, 4)]));
+        assert_eq!(procedure.ship, ship);
+    }
+
+    #[test]
+    fn render_ship() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        let drawing = EXAMPLE.split("\n\n").next().unwrap();
+        assert_eq!(procedure.ship.to_string(), format!("{drawing}\n"));
+    }
+
+    #[test]
+    fn wide_ship() {
+        // the drawing is not padded with trailing whitespace
+        let drawing = [
+            "                                       [Fe]",
+            "[A]                                    [Co]",
+            "[B] [C] [D] [E] [F] [G] [H] [I] [J] [K] [Ni]",
+            " 1   2   3   4   5   6   7   8   9   10  11",
+        ];
+        let input = format!("{}\n\nmove 2 from 11 to 10\n", drawing.join("\n"));
+        let procedure = parse(input.as_bytes()).unwrap();
+        assert_eq!(procedure.ship.stacks.len(), 11);
+        assert_eq!(procedure.ship.stacks[&11].get(1), Some(&Crate("Co".into())));
+        assert_eq!(part2(&procedure).unwrap(), "ACDEFGHIJFeNi");
+
+        // every column widens to fit the widest label
+        let rendered = [
+            "                                                  [Fe]",
+            "[A]                                               [Co]",
+            "[B]  [C]  [D]  [E]  [F]  [G]  [H]  [I]  [J]  [K]  [Ni]",
+            " 1    2    3    4    5    6    7    8    9    10   11 ",
+        ];
+        assert_eq!(procedure.ship.to_string(), rendered.join("\n") + "\n");
+    }
+
+    #[test]
+    fn invalid_drawings() {
+        let error = |drawing: &str| parse(format!("{drawing}\n\nmove 1 from 1 to 2\n").as_bytes());
+        assert!(error("[A] [B]\n 1   1").is_err());
+        assert!(error("[A] B\n 1   2").is_err());
+        assert!(error("[A] []\n 1   2").is_err());
+        assert!(error("[A]      [B]\n 1   2").is_err());
+        assert!(error("[AAAAAAA]\n 1   2").is_err());
+        assert!(error("[A] [B]\n 1   2").is_ok());
+    }
+
+    #[test]
+    fn highlight_moved_crates() {
+        let procedure = parse(EXAMPLE.as_bytes()).unwrap();
+        let mut frames = vec![];
+        rearrange_with(&procedure, &CrateMover9001, &mut |ship, step| {
+            frames.push((ship.clone(), step.map(|(_, m)| Highlight::from(m))));
+        })
+        .unwrap();
+        assert_eq!(frames.len(), 5);
+
+        let (ship, highlight) = &frames[1];
+        let expected = format!(
+            "\
+{GREEN}[D]{RESET}        
+[N] [C]    
+[Z] [M] [P]
+{GREEN} 1 {RESET} {RED} 2 {RESET}  3 
+"
+        );
+        assert_eq!(ship.draw_highlighted(&highlight.unwrap()), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_render_parse(
+            stacks in prop::collection::vec(prop::collection::vec("[A-Z]{1,3}", 0..=8), 1..=12)
+        ) {
+            let ship = Ship {
+                stacks: (1..)
+                    .zip(stacks)
+                    .map(|(num, labels)| {
+                        let mut stack = Stack::new();
+                        stack.extend(labels.into_iter().map(Crate));
+                        (num, stack)
+                    })
+                    .collect(),
+            };
+            let drawing = ship.to_string();
+            let parsed = parse(drawing.as_bytes()).unwrap().ship;
+            prop_assert_eq!(&parsed, &ship);
+            prop_assert_eq!(&parsed.to_string(), &drawing);
+
+            let trimmed: String = drawing.lines().map(|line| format!("{}\n", line.trim_end())).collect();
+            prop_assert_eq!(parse(trimmed.as_bytes()).unwrap().ship, ship);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Day 6: Tuning Trouble
+
+use log::{debug, trace};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::{Error, Result};
+
+/// Returns the number of characters processed before the first `size`
+/// consecutive distinct characters have been seen.
+pub fn find_marker(chars: &[char], size: usize) -> Option<usize> {
+    let mut start: usize = 0;
+    let mut map = HashMap::<char, usize>::new();
+
+    for (i, char) in chars.iter().enumerate() {
+        trace!(
+            "i: {i}, start: {start}, map: {map:?}, \t\t chars: {:?}",
+            &chars[start..i]
+        );
+
+        match map.get(char) {
+            Some(&duplicate_index) => {
+                trace!("duplicate found: {char}");
+                trace!("removing characters from the map from index {start} to {duplicate_index}");
+                for (j, c) in chars.iter().enumerate().take(duplicate_index).skip(start) {
+                    trace!("removing index {j} character {c}");
+                    map.remove(c);
+                }
+                start = duplicate_index + 1;
+                map.insert(*char, i);
+            }
+            None => {
+                map.insert(*char, i);
+            }
+        }
+
+        if map.len() == size {
+            debug!(
+                "done! we have found the marker at indexes {start} to {} with characters: {:?}",
+                i + 1,
+                &chars[start..=i]
+            );
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+/// Reads the datastream buffer, which is the first non-empty line of input.
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<char>> {
+    for line in reader.lines() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        return Ok(line.chars().collect());
+    }
+
+    Err(Error::EmptyInput { day: 6 })
+}
+
+/// Position of the first start-of-packet marker.
+pub fn part1(chars: &[char]) -> Result<usize> {
+    find_marker(chars, 4).ok_or_else(|| Error::solve(6, "no start-of-packet marker found"))
+}
+
+/// Position of the first start-of-message marker.
+pub fn part2(chars: &[char]) -> Result<usize> {
+    // I just changed `size` from 4 in part 1 to 14 in part 2.
+    find_marker(chars, 14).ok_or_else(|| Error::solve(6, "no start-of-message marker found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn example_part1() {
+        for (example, expected, _) in EXAMPLES {
+            let chars = parse(example.as_bytes()).unwrap();
+            assert_eq!(part1(&chars).unwrap(), expected, "{example}");
+        }
+    }
+
+    #[test]
+    fn example_part2() {
+        for (example, _, expected) in EXAMPLES {
+            let chars = parse(example.as_bytes()).unwrap();
+            assert_eq!(part2(&chars).unwrap(), expected, "{example}");
+        }
+    }
+}
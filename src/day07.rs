@@ -0,0 +1,312 @@
+//! Day 7: No Space Left On Device
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::rc::Rc;
+
+use derivative::Derivative;
+use itertools::Itertools;
+use log::debug;
+use parse_display::{Display, FromStr};
+
+use crate::error;
+use crate::{Error, Result};
+
+#[derive(Debug)]
+pub struct Filesystem {
+    pub root: Rc<Directory>,
+}
+
+impl Filesystem {
+    pub fn new() -> Self {
+        Filesystem {
+            root: Rc::new(Directory::new_root()),
+        }
+    }
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct File {
+    pub name: String,
+    pub size: u32,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Directory {
+    pub name: String,
+    #[derivative(Debug = "ignore")]
+    pub parent: Option<Rc<Directory>>,
+    pub children: RefCell<BTreeMap<String, Rc<Entry>>>,
+}
+
+impl Directory {
+    pub fn new_root() -> Self {
+        Directory {
+            name: "/".to_string(),
+            parent: None,
+            children: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn new(name: String, parent: Rc<Directory>) -> Directory {
+        Directory {
+            name,
+            parent: Some(parent),
+            children: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn get_subdirectory(&self, dir: &str) -> std::result::Result<Rc<Directory>, String> {
+        if dir == ".." {
+            self.parent.clone().ok_or_else(|| {
+                "expecting to have a parent directory when calling `cd ..`".to_string()
+            })
+        } else {
+            match self.children.borrow().get(dir) {
+                Some(entry) => match &**entry {
+                    Entry::Directory(directory) => Ok(Rc::clone(directory)),
+                    Entry::File(_) => Err(format!(
+                        "found a file with name {} but not a directory within {}",
+                        &dir, &self.name
+                    )),
+                },
+                None => Err(format!(
+                    "could not execute ChangeDirectory as no {} directory found within {}",
+                    &dir, &self.name
+                )),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Entry {
+    File(Rc<File>),
+    Directory(Rc<Directory>),
+}
+
+#[derive(Display, FromStr, PartialEq, Debug)]
+pub enum Command {
+    #[display("$ cd {0}")]
+    ChangeDirectory(String),
+    #[display("$ ls")]
+    ListDirectory,
+}
+
+struct ShellSession {
+    current_command: Command,
+    current_working_directory: Rc<Directory>,
+}
+
+/// Returns the total size of `dir`, pushing the size of every directory
+/// nested within it onto `size_map` along the way.
+pub fn get_filesize(dir: Rc<Directory>, size_map: &mut Vec<u32>) -> u32 {
+    let mut total = 0;
+    for entry in dir.children.borrow().values() {
+        total += match &**entry {
+            Entry::Directory(directory) => {
+                let size = get_filesize(Rc::clone(directory), size_map);
+                size_map.push(size);
+                size
+            }
+            Entry::File(file) => file.size,
+        }
+    }
+    total
+}
+
+/// Rebuilds the filesystem from the terminal output of a shell session.
+pub fn parse<R: BufRead>(reader: R) -> Result<Filesystem> {
+    let mut lines = reader.lines().enumerate();
+
+    // Breaking out the first line separately so that we can ensure that
+    // we are starting from the root directory.
+    // In addition, it allows us to set up the ShellSession without using
+    // Option.
+
+    let (_, first) = lines.next().ok_or(Error::EmptyInput { day: 7 })?;
+    let first = first?;
+    if first != "$ cd /" {
+        let message = "we are assuming that the first line is cding into the root directory";
+        return Err(Error::parse(7, 1, 1, &first, message));
+    }
+
+    let filesystem = Filesystem::new();
+    let mut session = ShellSession {
+        current_command: Command::ChangeDirectory("/".to_string()),
+        current_working_directory: Rc::clone(&filesystem.root),
+    };
+
+    for (i, line) in lines {
+        let line = line?;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        // input is a command, we should parse it and update the session
+        if line.starts_with('$') {
+            session.current_command = line.parse().map_err(|err| {
+                let message = format!("unable to parse command correctly: {err}");
+                Error::parse(7, i + 1, 1, &line, message)
+            })?;
+            if let Command::ChangeDirectory(dir) = &session.current_command {
+                let changed_directory = session
+                    .current_working_directory
+                    .get_subdirectory(dir)
+                    .map_err(|message| {
+                        // the directory name follows `$ cd `
+                        Error::parse(7, i + 1, 6, &line, message)
+                    })?;
+                session.current_working_directory = Rc::clone(&changed_directory);
+            }
+
+        // regular line of input, not a command
+        } else if let Command::ListDirectory = &session.current_command {
+            let mut split = line.split_ascii_whitespace();
+            let (Some(first), Some(name)) = (split.next(), split.next()) else {
+                let message = "expected `dir <name>` or `<size> <name>`";
+                return Err(Error::parse(7, i + 1, 1, &line, message));
+            };
+
+            let entry = if first == "dir" {
+                let parent = Rc::clone(&session.current_working_directory);
+                let directory = Rc::new(Directory::new(name.to_string(), parent));
+                Rc::new(Entry::Directory(directory))
+            } else {
+                let size: u32 = first.parse().map_err(|err| {
+                    let message = format!("invalid file size {first:?}: {err}");
+                    Error::parse(7, i + 1, error::column(&line, first), &line, message)
+                })?;
+                let file = Rc::new(File {
+                    name: name.to_string(),
+                    size,
+                });
+                Rc::new(Entry::File(file))
+            };
+
+            session
+                .current_working_directory
+                .children
+                .borrow_mut()
+                .insert(name.to_string(), entry);
+        }
+    }
+
+    Ok(filesystem)
+}
+
+/// Sum of the sizes of every directory of at most 100000.
+pub fn part1(filesystem: &Filesystem) -> Result<u32> {
+    // now iterate down the tree and get size of each directory
+    let mut directory_sizes = vec![];
+    let total = get_filesize(Rc::clone(&filesystem.root), &mut directory_sizes);
+    directory_sizes.push(total);
+
+    Ok(directory_sizes
+        .into_iter()
+        .filter(|size| *size <= 100000)
+        .sum::<u32>())
+}
+
+/// Size of the smallest directory that frees up enough space for the update.
+pub fn part2(filesystem: &Filesystem) -> Result<u32> {
+    let disk_space = 70000000_u32;
+    let required_space = 30000000_u32;
+
+    debug!("total disk space: {}", disk_space);
+    debug!("required disk space: {}", required_space);
+    let mut directory_sizes = vec![];
+    let actual = get_filesize(Rc::clone(&filesystem.root), &mut directory_sizes);
+    directory_sizes.push(actual);
+    debug!("actual: {}", actual);
+    let free = disk_space.checked_sub(actual).ok_or_else(|| {
+        let message = format!("{actual} is used which is more than the disk space available");
+        Error::solve(7, message)
+    })?;
+    debug!("free: {}", free);
+    let minimum = required_space.saturating_sub(free);
+    debug!("minimum size to delete: {}", minimum);
+    let size_of_directory_to_delete = directory_sizes
+        .into_iter()
+        .filter(|x| x >= &minimum)
+        .sorted()
+        .next()
+        .ok_or_else(|| Error::solve(7, "no directory is large enough to delete"))?;
+
+    Ok(size_of_directory_to_delete)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn directory_sizes() {
+        let filesystem = parse(EXAMPLE.as_bytes()).unwrap();
+        let mut directory_sizes = vec![];
+        let total = get_filesize(Rc::clone(&filesystem.root), &mut directory_sizes);
+        assert_eq!(total, 48381165);
+        // directories are visited in name order, innermost first
+        assert_eq!(directory_sizes, vec![584, 94853, 24933642]);
+    }
+
+    #[test]
+    fn example_part1() {
+        let filesystem = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&filesystem).unwrap(), 95437);
+    }
+
+    #[test]
+    fn example_part2() {
+        let filesystem = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&filesystem).unwrap(), 24933642);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("$ cd /\n$ ls\ndir a\n$ cd b\n".as_bytes())
+            .err()
+            .unwrap();
+        let expected = "\
+day 07, line 4, column 6: could not execute ChangeDirectory as no b directory found within /
+4 | $ cd b
+  |      ^";
+        assert_eq!(err.to_string(), expected);
+    }
+}
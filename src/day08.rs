@@ -0,0 +1,328 @@
+//! Day 8: Treetop Tree House
+
+use itertools::{Either, Itertools};
+use log::{debug, trace};
+use std::fmt;
+use std::fmt::Debug;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{Error, Result};
+
+#[derive(Debug, EnumIter)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+#[derive(Debug)]
+pub enum Axis {
+    Row,
+    Column,
+}
+
+impl Direction {
+    pub fn get_range(
+        &self,
+        size: i32,
+    ) -> (
+        impl Iterator<Item = usize> + Debug + Clone,
+        impl Iterator<Item = usize> + Debug + Clone,
+        Axis,
+    ) {
+        let last: usize = (size - 1).try_into().unwrap();
+
+        let forward = Either::Left(0..=last);
+        let reverse: Either<RangeInclusive<usize>, std::iter::Rev<RangeInclusive<usize>>> =
+            Either::Right((0..=last).rev());
+
+        match *self {
+            Direction::Right => (forward.clone(), forward, Axis::Row),
+            Direction::Down => (forward.clone(), forward, Axis::Column),
+            Direction::Left => (reverse.clone(), reverse, Axis::Row),
+            Direction::Up => (reverse.clone(), reverse, Axis::Column),
+        }
+    }
+
+    pub fn value(self) -> (i32, i32) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Cell<T> {
+    pub value: T,
+    pub pos: (usize, usize),
+}
+
+pub struct Grid<T>(pub Vec<Vec<Cell<T>>>);
+
+impl<T: Clone + Copy + Debug> Grid<T> {
+    pub fn new() -> Self {
+        Grid(vec![])
+    }
+
+    pub fn new_with_size(value: T, size: &usize) -> Self {
+        Grid(
+            (0..*size)
+                .map(|i| {
+                    (0..*size)
+                        .map(|j| Cell { value, pos: (i, j) })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T: Clone + Copy + Debug> Default for Grid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Grid<u32> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.0 {
+            for cell in row {
+                write!(f, "{}", cell.value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Grid<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.0 {
+            for cell in row {
+                write!(f, "{}", if cell.value { "T" } else { "F" })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: std::fmt::Debug + Clone> Grid<T> {
+    pub fn iterate_in_direction(&self, direction: Direction) -> Vec<Vec<Cell<T>>> {
+        let (x_range, y_range, axis) = direction.get_range(self.0.len().try_into().unwrap());
+
+        match axis {
+            Axis::Row => x_range
+                .map(|i| {
+                    y_range
+                        .clone()
+                        .map(|j| self.0[i][j].clone())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<Vec<_>>>(),
+            Axis::Column => y_range
+                .map(|j| {
+                    x_range
+                        .clone()
+                        .map(|i| self.0[i][j].clone())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<Vec<_>>>(),
+        }
+    }
+}
+
+/// Reads the tree heights into a square grid, one digit per tree.
+pub fn parse<R: BufRead>(reader: R) -> Result<Grid<u32>> {
+    let mut grid = Grid::<u32>::new();
+    let mut last_line = (0, String::new());
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(j, x)| {
+                let value = x.to_digit(10).ok_or_else(|| {
+                    let message = format!("invalid tree height: {x:?}");
+                    Error::parse(8, i + 1, j + 1, &line, message)
+                })?;
+                Ok(Cell {
+                    value,
+                    pos: (grid.0.len(), j),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(first) = grid.0.first() {
+            if row.len() != first.len() {
+                let message = format!("expected {} trees but found {}", first.len(), row.len());
+                let column = row.len().min(first.len()) + 1;
+                return Err(Error::parse(8, i + 1, column, &line, message));
+            }
+        }
+        grid.0.push(row);
+        last_line = (i + 1, line);
+    }
+
+    let Some(first) = grid.0.first() else {
+        return Err(Error::EmptyInput { day: 8 });
+    };
+    if grid.0.len() != first.len() {
+        let (i, line) = last_line;
+        let message = format!(
+            "expected a square grid but found {} rows of {} trees",
+            grid.0.len(),
+            first.len()
+        );
+        return Err(Error::parse(8, i, 1, &line, message));
+    }
+
+    Ok(grid)
+}
+
+/// Number of trees visible from outside the grid.
+pub fn part1(grid: &Grid<u32>) -> Result<u32> {
+    debug!("{}", &grid);
+
+    let size = grid.0.len();
+
+    debug!("Initialize Visibility to All False");
+    let mut visibility: Grid<bool> = Grid::new_with_size(false, &size);
+    debug!("{}", &visibility);
+
+    debug!("Set All Border to Visible");
+    (0..size).cartesian_product(0..size).for_each(|(i, j)| {
+        if i == 0 || j == 0 || i == size - 1 || j == size - 1 {
+            visibility.0[i][j].value = true;
+        }
+    });
+    debug!("{}", &visibility);
+
+    Direction::iter().for_each(|direction| {
+        debug!("Process {direction:?}");
+        for (k, mut axis) in grid.iterate_in_direction(direction).into_iter().enumerate() {
+            if k == 0 {
+                trace!("skipping first axis");
+                continue;
+            }
+            let (first, remaining) = axis.split_first_mut().unwrap();
+            let mut previous_height = first.value;
+            trace!("initializing previous height to {previous_height} from the first value in axis at position: {:?}", first.pos);
+            for cell in remaining {
+                trace!(
+                    "comparing cell.value ({}) to previous_height ({}) at position {:?}",
+                    cell.value, previous_height, cell.pos
+                );
+                if cell.value > previous_height {
+                    trace!("cell.value ({}) was larger than previous_height ({}). setting cell's visibility at position of {:?} to true", cell.value, previous_height, cell.pos);
+                    let (i, j) = cell.pos;
+                    visibility.0[i][j].value = true;
+                    previous_height = cell.value;
+                }
+            }
+        }
+        debug!("{}", &visibility);
+    });
+
+    let num_visible: u32 = visibility
+        .0
+        .into_iter()
+        .flatten()
+        .map(|x| x.value)
+        .filter(|&x| x)
+        .map(|x| x as u32)
+        .sum();
+
+    Ok(num_visible)
+}
+
+/// Highest scenic score of any tree in the grid.
+pub fn part2(grid: &Grid<u32>) -> Result<u32> {
+    let grid: Vec<Vec<u32>> = grid
+        .0
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.value).collect())
+        .collect();
+
+    let mut max_scenic_score = 0;
+
+    let height = grid.len();
+    let width = grid[0].len();
+
+    for i in 0..height {
+        for j in 0..width {
+            let start = grid[i][j];
+            let distances = Direction::iter()
+                .map(|dir| {
+                    let mut distance = 0;
+                    let (add_x, add_y) = dir.value();
+                    let (mut x, mut y) = (i, j);
+                    while x > 0 && x < width - 1 && y > 0 && y < height - 1 {
+                        x = usize::try_from((x as i32) + add_x).unwrap();
+                        y = usize::try_from((y as i32) + add_y).unwrap();
+                        let current = grid[x][y];
+                        distance += 1;
+                        if current >= start {
+                            break;
+                        }
+                    }
+                    distance
+                })
+                .collect_vec();
+            let product = distances.iter().product::<u32>();
+            max_scenic_score = max_scenic_score.max(product);
+        }
+    }
+
+    Ok(max_scenic_score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn example_part1() {
+        let grid = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&grid).unwrap(), 21);
+    }
+
+    #[test]
+    fn example_part2() {
+        let grid = parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part2(&grid).unwrap(), 8);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("303\n2x5\n653\n".as_bytes()).err().unwrap();
+        let expected = "\
+day 08, line 2, column 2: invalid tree height: 'x'
+2 | 2x5
+  |  ^";
+        assert_eq!(err.to_string(), expected);
+
+        let err = parse("303\n25\n653\n".as_bytes()).err().unwrap();
+        let expected = "\
+day 08, line 2, column 3: expected 3 trees but found 2
+2 | 25
+  |   ^";
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -0,0 +1,117 @@
+//! The error type shared by every day's parser and solvers.
+
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub enum Error {
+    /// The puzzle input could not be read.
+    Io(io::Error),
+
+    /// The puzzle input was empty.
+    EmptyInput { day: u8 },
+
+    /// A line of the puzzle input could not be parsed. `line` and `column`
+    /// are 1-based, and `text` holds the full offending line.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+
+    /// The puzzle input was well-formed but could not be solved.
+    Solve { day: u8, message: String },
+}
+
+impl Error {
+    pub fn parse(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        Error::Parse {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn solve(day: u8, message: impl Into<String>) -> Self {
+        Error::Solve {
+            day,
+            message: message.into(),
+        }
+    }
+}
+
+/// 1-based column at which `token`, a subslice of `line`, starts.
+pub(crate) fn column(line: &str, token: &str) -> usize {
+    token.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::EmptyInput { day } => write!(f, "day {day:02}: input is empty"),
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                // point at the offending column underneath the line, like rustc does
+                let gutter = line.to_string().len();
+                writeln!(f, "day {day:02}, line {line}, column {column}: {message}")?;
+                writeln!(f, "{line} | {text}")?;
+                write!(f, "{:gutter$} | {:>column$}", "", "^")
+            }
+            Error::Solve { day, message } => write!(f, "day {day:02}: {message}"),
+        }
+    }
+}
+
+// `main` reports errors through `Debug`, so use the readable diagnostic there too.
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_at_column() {
+        let err = Error::parse(5, 12, 6, "move x from 1 to 2", "invalid number");
+        let expected = "\
+day 05, line 12, column 6: invalid number
+12 | move x from 1 to 2
+   |      ^";
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -0,0 +1,56 @@
+//! Locating and reading puzzle input.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use crate::Result;
+
+/// Command line arguments selecting where to read the puzzle input from.
+#[derive(Parser, Debug, Default)]
+pub struct InputArgs {
+    /// Path to the puzzle input, or `-` to read it from stdin. Defaults to the
+    /// input bundled in the `resources` folder.
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    /// Reads the selected puzzle input for the given day.
+    pub fn read(&self, day: u8) -> Result<String> {
+        read(self.input.as_deref(), day)
+    }
+
+    /// Path the puzzle input for the given day is read from, `-` being stdin.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.input.clone().unwrap_or_else(|| resource_path(day))
+    }
+}
+
+/// Path of the puzzle input bundled with the repository for the given day.
+pub fn resource_path(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("resources")
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Reads the puzzle input from `path`, from stdin when `path` is `-`, or from
+/// the bundled resource for `day` when no path is given at all.
+pub fn read(path: Option<&Path>, day: u8) -> Result<String> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => read_file(path),
+        None => read_file(&resource_path(day)),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())).into())
+}
@@ -0,0 +1,268 @@
+//! Inclusive ranges of ids, such as the camp sections of day 4, along with
+//! sets of them kept merged into as few ranges as possible.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The inclusive range of ids `start..=end`, written as `start-end`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SectionRange {
+    start: u32,
+    end: u32,
+}
+
+impl SectionRange {
+    /// The range `start..=end`, if `start` does not come after `end`.
+    pub fn new(start: u32, end: u32) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Number of ids in the range.
+    pub fn size(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+
+    pub fn contains_id(&self, id: u32) -> bool {
+        self.start <= id && id <= self.end
+    }
+
+    /// Whether the other range lies entirely within this one.
+    pub fn contains(&self, other: &Self) -> bool {
+        //       o.start ... o.end
+        // [ start ............... end ]
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the ranges share at least one id.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        // they do not overlap when one ends before the other starts:
+        // 2-4            6-8
+        //     6-8   2-4
+        !(self.end < other.start || other.end < self.start)
+    }
+
+    /// Whether the ranges overlap or one starts right after the other ends,
+    /// so that together they cover a single range.
+    fn touches(&self, other: &Self) -> bool {
+        self.overlaps(other)
+            || self.end.checked_add(1) == Some(other.start)
+            || other.end.checked_add(1) == Some(self.start)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The ids in either range.
+    pub fn union(&self, other: &Self) -> IntervalSet {
+        IntervalSet::from_iter([*self, *other])
+    }
+
+    /// The ids in this range but not in the other, which leaves nothing, a
+    /// single range or a range either side of the other.
+    pub fn difference(&self, other: &Self) -> IntervalSet {
+        if !self.overlaps(other) {
+            return IntervalSet::from_iter([*self]);
+        }
+        let before = other
+            .start
+            .checked_sub(1)
+            .and_then(|end| Self::new(self.start, end));
+        let after = other
+            .end
+            .checked_add(1)
+            .and_then(|start| Self::new(start, self.end));
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl fmt::Display for SectionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl FromStr for SectionRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let Some((start, end)) = s.split_once('-') else {
+            return Err(format!("expected a range of the form start-end: {s:?}"));
+        };
+        let parse = |x: &str| {
+            x.parse::<u32>()
+                .map_err(|err| format!("invalid section id {x:?}: {err}"))
+        };
+        let (start, end) = (parse(start)?, parse(end)?);
+        Self::new(start, end).ok_or_else(|| format!("range {s:?} starts after it ends"))
+    }
+}
+
+/// A set of ids, stored as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<SectionRange>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> &[SectionRange] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of ids in the set.
+    pub fn size(&self) -> u64 {
+        self.ranges.iter().map(SectionRange::size).sum()
+    }
+
+    pub fn contains_id(&self, id: u32) -> bool {
+        self.ranges.iter().any(|range| range.contains_id(id))
+    }
+
+    /// Adds the ids of the range, merging it with the ranges it touches.
+    pub fn insert(&mut self, range: SectionRange) {
+        let mut merged = range;
+        self.ranges.retain(|existing| {
+            if existing.touches(&merged) {
+                merged = SectionRange {
+                    start: existing.start.min(merged.start),
+                    end: existing.end.max(merged.end),
+                };
+                false
+            } else {
+                true
+            }
+        });
+        let i = self
+            .ranges
+            .partition_point(|existing| existing.start < merged.start);
+        self.ranges.insert(i, merged);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut output = Self::new();
+        for range in &self.ranges {
+            for other in &other.ranges {
+                if let Some(intersection) = range.intersection(other) {
+                    output.insert(intersection);
+                }
+            }
+        }
+        output
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut remaining = self.clone();
+        for other in &other.ranges {
+            remaining = remaining
+                .ranges
+                .iter()
+                .flat_map(|range| range.difference(other).ranges)
+                .collect();
+        }
+        remaining
+    }
+}
+
+impl Extend<SectionRange> for IntervalSet {
+    fn extend<I: IntoIterator<Item = SectionRange>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl FromIterator<SectionRange> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = SectionRange>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> SectionRange {
+        s.parse().unwrap()
+    }
+
+    fn set(s: &str) -> IntervalSet {
+        s.split(',').map(range).collect()
+    }
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(range("2-4"), SectionRange::new(2, 4).unwrap());
+        assert_eq!(range("6-6").size(), 1);
+        assert!("4-2".parse::<SectionRange>().is_err());
+        assert!("4".parse::<SectionRange>().is_err());
+        assert!("a-2".parse::<SectionRange>().is_err());
+    }
+
+    #[test]
+    fn compare_ranges() {
+        assert!(range("2-8").contains(&range("3-7")));
+        assert!(!range("3-7").contains(&range("2-8")));
+        assert!(range("5-7").overlaps(&range("7-9")));
+        assert!(!range("2-3").overlaps(&range("4-5")));
+        assert_eq!(range("5-7").intersection(&range("7-9")), Some(range("7-7")));
+        assert_eq!(range("2-3").intersection(&range("4-5")), None);
+    }
+
+    #[test]
+    fn combine_ranges() {
+        assert_eq!(range("2-3").union(&range("4-5")), set("2-5"));
+        assert_eq!(range("2-3").union(&range("6-7")), set("2-3,6-7"));
+        assert_eq!(range("2-8").difference(&range("4-5")), set("2-3,6-8"));
+        assert_eq!(range("2-8").difference(&range("0-5")), set("6-8"));
+        assert!(range("3-4").difference(&range("2-8")).is_empty());
+        assert_eq!(range("0-0").difference(&range("0-0")), IntervalSet::new());
+    }
+
+    #[test]
+    fn interval_sets() {
+        let a = set("9-12,1-3,5-6,4-4");
+        assert_eq!(a.to_string(), "1-6,9-12");
+        assert_eq!(a.size(), 10);
+        assert!(a.contains_id(10) && !a.contains_id(7));
+
+        let b = set("3-10");
+        assert_eq!(a.union(&b), set("1-12"));
+        assert_eq!(a.intersection(&b), set("3-6,9-10"));
+        assert_eq!(a.difference(&b), set("1-2,11-12"));
+    }
+}
@@ -0,0 +1,26 @@
+//! Solutions to the [Advent of Code 2022](https://adventofcode.com/2022) puzzles.
+//!
+//! Each day lives in its own module and exposes the parsed puzzle model along
+//! with a `parse` function and `part1`/`part2` solvers, so the binaries in
+//! `src/bin` are only thin wrappers around the library.
+
+pub mod answers;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod error;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod paragraphs;
+pub mod report;
+pub mod rps;
+pub mod runner;
+pub mod sorted_topk;
+
+pub use error::{Error, Result};
@@ -0,0 +1,40 @@
+//! Verbosity flags controlling the narration the solvers log while solving.
+//!
+//! Only answers are printed to stdout. Narration is logged to stderr at the
+//! `debug` level, with the very detailed step-by-step narration at `trace`.
+
+use clap::{ArgAction, Args};
+use env_logger::Env;
+use log::LevelFilter;
+
+#[derive(Args, Debug, Default)]
+pub struct Verbosity {
+    /// Narrate what the solvers are doing. Repeat for step-by-step tracing.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Narrate every step the solvers take, same as `-vv`.
+    #[arg(long, global = true)]
+    pub trace: bool,
+}
+
+impl Verbosity {
+    /// The level selected by the flags, if any were given.
+    pub fn level(&self) -> Option<LevelFilter> {
+        match (self.trace, self.verbose) {
+            (true, _) | (_, 2..) => Some(LevelFilter::Trace),
+            (false, 1) => Some(LevelFilter::Debug),
+            (false, 0) => None,
+        }
+    }
+
+    /// Sets up logging to stderr. Without any flags the level is taken from
+    /// `RUST_LOG`, defaulting to warnings only.
+    pub fn init(&self) {
+        let mut builder = env_logger::Builder::from_env(Env::default().default_filter_or("warn"));
+        if let Some(level) = self.level() {
+            builder.filter_level(level);
+        }
+        builder.format_timestamp(None).format_target(false).init();
+    }
+}
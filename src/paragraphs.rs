@@ -0,0 +1,133 @@
+//! Streaming reader for input made up of blank-line-separated sections, such
+//! as the elves of day 1 or the ship drawing and procedure of day 5.
+
+use std::io::{self, BufRead, Lines};
+
+/// A run of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paragraph {
+    /// Line number of the first line, counting from 1.
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Paragraph {
+    /// Every line along with its line number, counting from 1.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| (self.line + i, line.as_str()))
+    }
+}
+
+/// Iterator over the paragraphs of a reader, see [`paragraphs`].
+pub struct Paragraphs<R> {
+    lines: Lines<R>,
+    line: usize,
+}
+
+/// Splits the reader into paragraphs, reading one paragraph at a time.
+///
+/// Lines holding nothing but whitespace separate paragraphs. Any number of
+/// them may appear between paragraphs as well as before the first or after the
+/// last, which does not need to end with a newline. Lines may end in `\r\n`.
+pub fn paragraphs<R: BufRead>(reader: R) -> Paragraphs<R> {
+    Paragraphs {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Paragraphs<R> {
+    type Item = io::Result<Paragraph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut paragraph = Paragraph {
+            line: 0,
+            lines: vec![],
+        };
+
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let mut line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if line.trim().is_empty() {
+                if paragraph.lines.is_empty() {
+                    continue;
+                }
+                break;
+            }
+
+            // `lines` already drops the `\r` of a `\r\n` line ending, but not
+            // of a lone `\r` left over from an odd mix of line endings
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            if paragraph.lines.is_empty() {
+                paragraph.line = self.line;
+            }
+            paragraph.lines.push(line);
+        }
+
+        if paragraph.lines.is_empty() {
+            None
+        } else {
+            Some(Ok(paragraph))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(input: &str) -> Vec<Paragraph> {
+        paragraphs(input.as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    fn paragraph(line: usize, lines: &[&str]) -> Paragraph {
+        Paragraph {
+            line,
+            lines: lines.iter().map(|line| line.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let expected = vec![paragraph(1, &["a", "b"]), paragraph(4, &["c"])];
+        assert_eq!(collect("a\nb\n\nc\n"), expected);
+        assert_eq!(collect("a\nb\n\nc"), expected);
+        assert_eq!(collect("a\nb\n\nc\n\n\n"), expected);
+    }
+
+    #[test]
+    fn skips_runs_of_blank_lines() {
+        let expected = vec![paragraph(3, &["a"]), paragraph(7, &["  b", "c  "])];
+        assert_eq!(collect("\n  \na\n\n\t\n\n  b\nc  \n"), expected);
+    }
+
+    #[test]
+    fn handles_crlf() {
+        let expected = vec![paragraph(1, &["a", "b"]), paragraph(4, &["c"])];
+        assert_eq!(collect("a\r\nb\r\n\r\nc\r\n"), expected);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(collect(""), vec![]);
+        assert_eq!(collect("\n\n"), vec![]);
+    }
+
+    #[test]
+    fn numbered() {
+        let paragraph = paragraph(4, &["a", "b"]);
+        let lines: Vec<(usize, &str)> = paragraph.numbered().collect();
+        assert_eq!(lines, vec![(4, "a"), (5, "b")]);
+    }
+}
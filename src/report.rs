@@ -0,0 +1,29 @@
+//! Reports that analyse a whole puzzle input rather than boil it down to a
+//! single answer, printed either as a human readable table or as CSV.
+
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Aligned tables meant to be read in a terminal.
+    #[default]
+    Table,
+    /// Comma separated values with a header row, meant for further analysis.
+    Csv,
+}
+
+pub trait Report {
+    fn write_table(&self, writer: &mut dyn Write) -> io::Result<()>;
+
+    fn write_csv(&self, writer: &mut dyn Write) -> io::Result<()>;
+
+    /// Writes the report in the given format.
+    fn write(&self, format: Format, writer: &mut dyn Write) -> io::Result<()> {
+        match format {
+            Format::Table => self.write_table(writer),
+            Format::Csv => self.write_csv(writer),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Rules engine for rock paper scissors style games.
+//!
+//! A game is an ordered cycle of shapes in which every shape beats the half of
+//! the other shapes coming right before it, wrapping around at the start. The
+//! classic game is Rock, Paper, Scissors: Paper beats Rock, Scissors beats
+//! Paper and Rock beats Scissors. Rock Paper Scissors Lizard Spock is the
+//! cycle Rock, Spock, Paper, Lizard, Scissors.
+
+use std::fmt;
+
+/// The outcome of a round for one of the players.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Tie,
+    Win,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<S> {
+    shapes: Vec<S>,
+}
+
+impl Game<usize> {
+    /// A game of `n` shapes numbered `0..n`, where `n` must be odd.
+    pub fn cyclic(n: usize) -> std::result::Result<Self, String> {
+        Self::new((0..n).collect())
+    }
+}
+
+impl<S: Copy + PartialEq + fmt::Debug> Game<S> {
+    /// A game played with the given cycle of shapes. There must be an odd
+    /// number of distinct shapes, otherwise some pairs would have no winner.
+    pub fn new(shapes: Vec<S>) -> std::result::Result<Self, String> {
+        if shapes.len().is_multiple_of(2) {
+            return Err(format!(
+                "a game needs an odd number of shapes but got {}",
+                shapes.len()
+            ));
+        }
+        for (i, shape) in shapes.iter().enumerate() {
+            if shapes[..i].contains(shape) {
+                return Err(format!("{shape:?} appears more than once"));
+            }
+        }
+        Ok(Self { shapes })
+    }
+
+    pub fn shapes(&self) -> &[S] {
+        &self.shapes
+    }
+
+    /// Position of the shape in the cycle.
+    ///
+    /// Panics if the shape is not part of the game.
+    fn position(&self, shape: S) -> usize {
+        self.shapes
+            .iter()
+            .position(|&s| s == shape)
+            .unwrap_or_else(|| panic!("{shape:?} is not part of the game"))
+    }
+
+    /// The outcome for me when playing `me` against `opponent`.
+    ///
+    /// Panics if either shape is not part of the game.
+    pub fn outcome(&self, me: S, opponent: S) -> Outcome {
+        let n = self.shapes.len();
+        let distance = (self.position(me) + n - self.position(opponent)) % n;
+        match distance {
+            0 => Outcome::Tie,
+            d if d <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// Every shape I could play against `opponent` to end up with `outcome`,
+    /// in cycle order. There is exactly one in the classic game.
+    ///
+    /// Panics if the shape is not part of the game.
+    pub fn shapes_for(&self, opponent: S, outcome: Outcome) -> impl Iterator<Item = S> + '_ {
+        // validate up front rather than lazily while iterating
+        self.position(opponent);
+        self.shapes
+            .iter()
+            .copied()
+            .filter(move |&me| self.outcome(me, opponent) == outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic() {
+        let game = Game::new(vec!["rock", "paper", "scissors"]).unwrap();
+        assert_eq!(game.outcome("paper", "rock"), Outcome::Win);
+        assert_eq!(game.outcome("rock", "paper"), Outcome::Loss);
+        assert_eq!(game.outcome("rock", "scissors"), Outcome::Win);
+        assert_eq!(game.outcome("scissors", "scissors"), Outcome::Tie);
+
+        let shapes: Vec<&str> = game.shapes_for("rock", Outcome::Loss).collect();
+        assert_eq!(shapes, vec!["scissors"]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let game = Game::new(vec!["rock", "spock", "paper", "lizard", "scissors"]).unwrap();
+        assert_eq!(game.outcome("scissors", "lizard"), Outcome::Win);
+        assert_eq!(game.outcome("lizard", "spock"), Outcome::Win);
+        assert_eq!(game.outcome("spock", "scissors"), Outcome::Win);
+        assert_eq!(game.outcome("rock", "spock"), Outcome::Loss);
+
+        let shapes: Vec<&str> = game.shapes_for("paper", Outcome::Win).collect();
+        assert_eq!(shapes, vec!["lizard", "scissors"]);
+    }
+
+    #[test]
+    fn every_shape_wins_and_loses_equally() {
+        let game = Game::cyclic(7).unwrap();
+        for &opponent in game.shapes() {
+            assert_eq!(game.shapes_for(opponent, Outcome::Win).count(), 3);
+            assert_eq!(game.shapes_for(opponent, Outcome::Loss).count(), 3);
+            assert_eq!(game.shapes_for(opponent, Outcome::Tie).count(), 1);
+        }
+    }
+
+    #[test]
+    fn invalid_games() {
+        assert!(Game::cyclic(4).is_err());
+        assert!(Game::new(vec!["rock", "rock", "paper"]).is_err());
+    }
+}
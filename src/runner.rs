@@ -0,0 +1,95 @@
+//! Registry of every implemented solution, used by the `aoc` runner to
+//! dispatch to a day and part without knowing the individual binaries.
+
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+use crate::input::InputArgs;
+use crate::logging::Verbosity;
+use crate::Result;
+use crate::{day01, day02, day03, day04, day05, day06, day07, day08};
+
+/// Parses the puzzle input and solves one part of a day, returning the answer.
+pub type Solver = fn(&mut dyn BufRead) -> Result<Solved>;
+
+/// The answer to one part of a day, along with how long it took to get there.
+#[derive(Debug)]
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// A single part of a single day.
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    pub solve: Solver,
+}
+
+macro_rules! solution {
+    ($day:literal, $module:ident, $part:literal, $solver:ident) => {
+        Solution {
+            day: $day,
+            part: $part,
+            solve: |reader| {
+                let start = Instant::now();
+                let input = $module::parse(reader)?;
+                let parsed = Instant::now();
+                let answer = $module::$solver(&input)?.to_string();
+                Ok(Solved {
+                    answer,
+                    parse_time: parsed - start,
+                    solve_time: parsed.elapsed(),
+                })
+            },
+        }
+    };
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(1, day01, 1, part1),
+    solution!(1, day01, 2, part2),
+    solution!(2, day02, 1, part1),
+    solution!(2, day02, 2, part2),
+    solution!(3, day03, 1, part1),
+    solution!(3, day03, 2, part2),
+    solution!(4, day04, 1, part1),
+    solution!(4, day04, 2, part2),
+    solution!(5, day05, 1, part1),
+    solution!(5, day05, 2, part2),
+    solution!(6, day06, 1, part1),
+    solution!(6, day06, 2, part2),
+    solution!(7, day07, 1, part1),
+    solution!(7, day07, 2, part2),
+    solution!(8, day08, 1, part1),
+    solution!(8, day08, 2, part2),
+];
+
+/// Every solution matching the given day and, optionally, the given part.
+pub fn find(day: u8, part: Option<u8>) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS
+        .iter()
+        .filter(move |s| s.day == day && part.is_none_or(|part| s.part == part))
+}
+
+impl Solution {
+    /// Solves this part against the given puzzle input.
+    pub fn run(&self, input: &str) -> Result<Solved> {
+        (self.solve)(&mut input.as_bytes())
+    }
+}
+
+/// Solves a single part of one day's puzzle.
+///
+/// These are the command line arguments shared by the per-part binaries.
+#[derive(Parser, Debug, Default)]
+pub struct SolverArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    #[command(flatten)]
+    pub verbosity: Verbosity,
+}
@@ -0,0 +1,345 @@
+//! A collection that only keeps the `k` largest items pushed into it.
+//!
+//! Internally this is a
+//! [min-heap](https://doc.rust-lang.org/stable/std/collections/struct.BinaryHeap.html#min-heap)
+//! of at most `k` items, so pushing is `O(log k)` and memory stays bounded
+//! no matter how many items go in.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// Keeps the `k` largest items pushed into it.
+///
+/// ```
+/// use advent_of_code_2022::sorted_topk::SortedTopK;
+///
+/// let topk = SortedTopK::with_k(3, [5, 1, 8, 3, 9]);
+/// assert_eq!(topk.into_sorted_vec(), vec![9, 8, 5]);
+/// ```
+#[derive(Clone)]
+pub struct SortedTopK<T: Ord> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+/// Keeps the `k` smallest items pushed into it, each wrapped in [`Reverse`].
+pub type SortedBottomK<T> = SortedTopK<Reverse<T>>;
+
+impl<T: Ord> SortedTopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    /// The `k` largest of the given items.
+    pub fn with_k<I: IntoIterator<Item = T>>(k: usize, iter: I) -> Self {
+        let mut topk = Self::new(k);
+        topk.extend(iter);
+        topk
+    }
+
+    /// Most items held at once.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn push(&mut self, x: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(x));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            // only replace the smallest item we are holding onto if the new
+            // item beats it, otherwise the new item is not in the top k
+            if x > smallest.0 {
+                *smallest = Reverse(x);
+            }
+        }
+    }
+
+    /// Number of items held, which is less than `k` if fewer were pushed.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// The items held, largest first, without consuming the collection.
+    pub fn peek_sorted(&self) -> Vec<&T> {
+        let mut output: Vec<&T> = self.heap.iter().map(|x| &x.0).collect();
+        output.sort_by(|a, b| b.cmp(a));
+        output
+    }
+
+    /// The items held, largest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // sorting ascending by `Reverse` leaves the largest item first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|x| x.0)
+            .collect()
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for SortedTopK<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.peek_sorted()).finish()
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedTopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+/// A [`SortedTopK`] whose `k` is fixed at compile time, so that it can be
+/// built with `collect()`.
+///
+/// ```
+/// use advent_of_code_2022::sorted_topk::SortedTopKN;
+///
+/// let topk: SortedTopKN<i32, 3> = [5, 1, 8, 3, 9].into_iter().collect();
+/// assert_eq!(topk.into_sorted_vec(), vec![9, 8, 5]);
+/// ```
+#[derive(Clone)]
+pub struct SortedTopKN<T: Ord, const K: usize>(SortedTopK<T>);
+
+impl<T: Ord, const K: usize> SortedTopKN<T, K> {
+    pub fn new() -> Self {
+        Self(SortedTopK::new(K))
+    }
+
+    /// The collection with `k` known only at runtime.
+    pub fn into_inner(self) -> SortedTopK<T> {
+        self.0
+    }
+
+    /// The items held, largest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.0.into_sorted_vec()
+    }
+}
+
+impl<T: Ord, const K: usize> Deref for SortedTopKN<T, K> {
+    type Target = SortedTopK<T>;
+
+    fn deref(&self) -> &SortedTopK<T> {
+        &self.0
+    }
+}
+
+impl<T: Ord, const K: usize> DerefMut for SortedTopKN<T, K> {
+    fn deref_mut(&mut self) -> &mut SortedTopK<T> {
+        &mut self.0
+    }
+}
+
+impl<T: Ord, const K: usize> Default for SortedTopKN<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + fmt::Debug, const K: usize> fmt::Debug for SortedTopKN<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Ord, const K: usize> Extend<T> for SortedTopKN<T, K> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: Ord, const K: usize> FromIterator<T> for SortedTopKN<T, K> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut topk = Self::new();
+        topk.extend(iter);
+        topk
+    }
+}
+
+/// An item ordered only by its key.
+struct Keyed<B, T> {
+    key: B,
+    value: T,
+}
+
+impl<B: Ord, T> PartialEq for Keyed<B, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<B: Ord, T> Eq for Keyed<B, T> {}
+
+impl<B: Ord, T> PartialOrd for Keyed<B, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<B: Ord, T> Ord for Keyed<B, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Keeps the `k` items with the largest key, as computed by the key function.
+/// Items with equal keys are kept in no particular order. There is no
+/// `FromIterator` impl, since `collect()` has no way to pass the key function:
+/// create the collection with [`SortedTopKByKey::new`] and `extend` it instead.
+///
+/// ```
+/// use advent_of_code_2022::sorted_topk::SortedTopKByKey;
+///
+/// let mut longest = SortedTopKByKey::new(2, |s: &&str| s.len());
+/// longest.extend(["a", "abcd", "ab", "abc"]);
+/// assert_eq!(longest.into_sorted_vec(), vec!["abcd", "abc"]);
+/// ```
+pub struct SortedTopKByKey<T, B, F>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    inner: SortedTopK<Keyed<B, T>>,
+    key: F,
+}
+
+impl<T, B, F> SortedTopKByKey<T, B, F>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    pub fn new(k: usize, key: F) -> Self {
+        Self {
+            inner: SortedTopK::new(k),
+            key,
+        }
+    }
+
+    /// Most items held at once.
+    pub fn k(&self) -> usize {
+        self.inner.k()
+    }
+
+    pub fn push(&mut self, value: T) {
+        let key = (self.key)(&value);
+        self.inner.push(Keyed { key, value });
+    }
+
+    /// Number of items held, which is less than `k` if fewer were pushed.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// The items held, largest key first, without consuming the collection.
+    pub fn peek_sorted(&self) -> Vec<&T> {
+        self.inner
+            .peek_sorted()
+            .into_iter()
+            .map(|x| &x.value)
+            .collect()
+    }
+
+    /// The items held, largest key first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.inner
+            .into_sorted_vec()
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+}
+
+impl<T, B, F> Extend<T> for SortedTopKByKey<T, B, F>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_largest_items() {
+        let mut topk = SortedTopK::new(3);
+        for x in [4, 10, 1, 7, 3, 9] {
+            topk.push(x);
+        }
+        assert_eq!(topk.len(), 3);
+        assert_eq!(topk.into_sorted_vec(), vec![10, 9, 7]);
+    }
+
+    #[test]
+    fn fewer_than_k_items() {
+        let topk = SortedTopK::with_k(3, [2, 5]);
+        assert_eq!(topk.into_sorted_vec(), vec![5, 2]);
+
+        let empty = SortedTopK::<i32>::new(3);
+        assert!(empty.is_empty());
+        assert_eq!(empty.into_sorted_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn peek_sorted_is_not_destructive() {
+        let mut topk = SortedTopK::with_k(2, [1, 3, 2]);
+        assert_eq!(topk.peek_sorted(), vec![&3, &2]);
+        assert_eq!(topk.peek_sorted(), vec![&3, &2]);
+
+        topk.extend([5, 0]);
+        assert_eq!(topk.peek_sorted(), vec![&5, &3]);
+    }
+
+    #[test]
+    fn zero_k_keeps_nothing() {
+        let topk = SortedTopK::with_k(0, [1, 2, 3]);
+        assert!(topk.is_empty());
+    }
+
+    #[test]
+    fn collect_with_const_k() {
+        let mut topk: SortedTopKN<i32, 3> = [4, 10, 1, 7, 3, 9].into_iter().collect();
+        assert_eq!(topk.len(), 3);
+        assert_eq!(topk.k(), 3);
+        topk.push(8);
+        assert_eq!(topk.peek_sorted(), vec![&10, &9, &8]);
+
+        let topk = topk.into_inner();
+        assert_eq!(topk.into_sorted_vec(), vec![10, 9, 8]);
+    }
+
+    #[test]
+    fn bottom_k() {
+        let bottomk: SortedBottomK<i32> = SortedTopK::with_k(2, [4, 10, 1, 7].map(Reverse));
+        assert_eq!(bottomk.into_sorted_vec(), vec![Reverse(1), Reverse(4)]);
+    }
+
+    #[test]
+    fn by_key() {
+        let mut topk = SortedTopKByKey::new(2, |(_, calories): &(usize, i32)| *calories);
+        topk.extend([(0, 6000), (1, 4000), (2, 11000), (3, 24000), (4, 10000)]);
+        assert_eq!(topk.peek_sorted(), vec![&(3, 24000), &(2, 11000)]);
+        assert_eq!(topk.into_sorted_vec(), vec![(3, 24000), (2, 11000)]);
+    }
+}